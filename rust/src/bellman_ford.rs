@@ -0,0 +1,203 @@
+use std::collections::VecDeque;
+
+// edge with a possibly negative cost, used as a plain edge list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignedEdge {
+    pub start: usize,
+    pub end: usize,
+    pub cost: i64,
+}
+
+impl SignedEdge {
+    pub fn new(start: usize, end: usize, cost: i64) -> Self {
+        SignedEdge { start, end, cost }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SignedShortestPaths {
+    // minimum cost from source, None when unreachable
+    // meaningless for nodes marked unbounded
+    pub cost: Vec<Option<i64>>,
+    // a negative cycle reachable from the source also reaches
+    // this node, so its cost can be made arbitrarily small
+    pub unbounded: Vec<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathCost {
+    Unreachable,
+    Finite(i64),
+    // a negative cycle lies on the way to the target
+    Unbounded,
+}
+
+impl SignedShortestPaths {
+    pub fn cost_to(&self, target: usize) -> PathCost {
+        match self.cost[target] {
+            None => PathCost::Unreachable,
+            Some(_) if self.unbounded[target] => PathCost::Unbounded,
+            Some(cost) => PathCost::Finite(cost),
+        }
+    }
+}
+
+// bellman ford from a single source, O(n m)
+// after n - 1 rounds every bounded cost is final, so any edge
+// that still relaxes leads from a negative cycle and everything
+// reachable from its end is unbounded
+pub fn bellman_ford(n: usize, edges: &[SignedEdge], source: usize) -> SignedShortestPaths {
+    let mut cost: Vec<Option<i64>> = vec![None; n];
+    cost[source] = Some(0);
+
+    for _ in 1..n {
+        let mut changed = false;
+
+        for edge in edges {
+            if let Some(start_cost) = cost[edge.start] {
+                let next_cost = start_cost + edge.cost;
+                if cost[edge.end].is_none_or(|end_cost| next_cost < end_cost) {
+                    cost[edge.end] = Some(next_cost);
+                    changed = true;
+                }
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    let mut adjacent = vec![Vec::new(); n];
+    let mut unbounded = vec![false; n];
+    let mut pending = Vec::new();
+
+    for edge in edges {
+        adjacent[edge.start].push(edge.end);
+
+        if let (Some(start_cost), Some(end_cost)) = (cost[edge.start], cost[edge.end]) {
+            if start_cost + edge.cost < end_cost && !unbounded[edge.end] {
+                unbounded[edge.end] = true;
+                pending.push(edge.end);
+            }
+        }
+    }
+
+    while let Some(node) = pending.pop() {
+        for &next in adjacent[node].iter() {
+            if !unbounded[next] {
+                unbounded[next] = true;
+                pending.push(next);
+            }
+        }
+    }
+
+    SignedShortestPaths { cost, unbounded }
+}
+
+// queue based bellman ford, usually much faster in practice
+// returns the costs or a negative cycle reachable from the source
+// a shortest path of n edges repeats a node and such a repeat only
+// improves the cost around a negative cycle, so the edge count of the
+// current path of every node is tracked, relaxation counts are not
+// enough as parallel edges relax a node several times in one pass
+pub fn spfa(n: usize, edges: &[SignedEdge], source: usize) -> Result<Vec<Option<i64>>, Vec<usize>> {
+    let mut adjacent = vec![Vec::new(); n];
+    for edge in edges {
+        adjacent[edge.start].push(*edge);
+    }
+
+    let mut cost: Vec<Option<i64>> = vec![None; n];
+    // edges on the path giving the current cost of every node
+    let mut path_len = vec![0; n];
+    let mut in_queue = vec![false; n];
+    let mut queue = VecDeque::new();
+
+    cost[source] = Some(0);
+    queue.push_back(source);
+    in_queue[source] = true;
+
+    while let Some(node) = queue.pop_front() {
+        in_queue[node] = false;
+        let node_cost = cost[node].unwrap();
+
+        for edge in adjacent[node].iter() {
+            let next_cost = node_cost + edge.cost;
+
+            if cost[edge.end].is_none_or(|end_cost| next_cost < end_cost) {
+                cost[edge.end] = Some(next_cost);
+                path_len[edge.end] = path_len[node] + 1;
+
+                if path_len[edge.end] >= n {
+                    let reached_edges: Vec<SignedEdge> = edges
+                        .iter()
+                        .filter(|edge| cost[edge.start].is_some())
+                        .copied()
+                        .collect();
+
+                    if let Some(cycle) = find_negative_cycle(n, &reached_edges) {
+                        return Err(cycle);
+                    }
+                }
+
+                if !in_queue[edge.end] {
+                    in_queue[edge.end] = true;
+                    queue.push_back(edge.end);
+                }
+            }
+        }
+    }
+
+    Ok(cost)
+}
+
+// any negative cycle in the graph, not only those reachable from
+// a particular source, nodes are returned in the cycle order
+// and the first node is repeated at the end
+// starting every node at cost 0 acts like a virtual source with
+// zero cost edges to all nodes
+pub fn find_negative_cycle(n: usize, edges: &[SignedEdge]) -> Option<Vec<usize>> {
+    let mut cost = vec![0i64; n];
+    let mut parent = vec![usize::MAX; n];
+    let mut last_relaxed = usize::MAX;
+
+    for _ in 0..n {
+        last_relaxed = usize::MAX;
+
+        for edge in edges {
+            let next_cost = cost[edge.start] + edge.cost;
+            if next_cost < cost[edge.end] {
+                cost[edge.end] = next_cost;
+                parent[edge.end] = edge.start;
+                last_relaxed = edge.end;
+            }
+        }
+
+        if last_relaxed == usize::MAX {
+            return None;
+        }
+    }
+
+    Some(cycle_from_parents(&parent, last_relaxed))
+}
+
+// walking parents n times from a node whose chain contains a cycle
+// is guaranteed to land on the cycle, then follow it once around
+fn cycle_from_parents(parent: &[usize], start: usize) -> Vec<usize> {
+    let mut on_cycle = start;
+    for _ in 0..parent.len() {
+        on_cycle = parent[on_cycle];
+    }
+
+    let mut cycle = vec![on_cycle];
+    let mut cur = parent[on_cycle];
+    while cur != on_cycle {
+        cycle.push(cur);
+        cur = parent[cur];
+    }
+    cycle.push(on_cycle);
+
+    // parents were followed backwards
+    cycle.reverse();
+    cycle
+}
@@ -0,0 +1,243 @@
+// bridges, articulation points and biconnected components of an
+// undirected multigraph, computed with one iterative low link dfs
+// so deep path graphs do not overflow the stack
+// reference - https://cp-algorithms.com/graph/bridge-searching.html
+#[derive(Debug, Clone)]
+pub struct LowLink {
+    n: usize,
+    edges: Vec<(usize, usize)>,
+    // entry time of each node in the dfs
+    pub tin: Vec<usize>,
+    // smallest entry time reachable from the subtree of a node with
+    // at most one back edge
+    pub low: Vec<usize>,
+    is_bridge: Vec<bool>,
+    is_articulation: Vec<bool>,
+    // node sets of the biconnected components
+    blocks: Vec<Vec<usize>>,
+}
+
+impl LowLink {
+    // edges are given as node pairs, their positions are used as edge ids
+    // parallel edges are kept, self loops are ignored
+    pub fn new(n: usize, edges: &[(usize, usize)]) -> Self {
+        let mut adjacent = vec![Vec::new(); n];
+        for (id, &(a, b)) in edges.iter().enumerate() {
+            if a != b {
+                adjacent[a].push((b, id));
+                adjacent[b].push((a, id));
+            }
+        }
+
+        let mut tin = vec![usize::MAX; n];
+        let mut low = vec![0; n];
+        let mut is_bridge = vec![false; edges.len()];
+        let mut is_articulation = vec![false; n];
+        let mut blocks = Vec::new();
+        let mut timer = 0;
+
+        // dfs frames of (node, edge id used to enter it, next edge index)
+        let mut stack: Vec<(usize, usize, usize)> = Vec::new();
+        // edges of the biconnected component being built
+        let mut edge_stack: Vec<usize> = Vec::new();
+
+        for root in 0..n {
+            if tin[root] != usize::MAX {
+                continue;
+            }
+
+            tin[root] = timer;
+            low[root] = timer;
+            timer += 1;
+
+            if adjacent[root].is_empty() {
+                blocks.push(vec![root]);
+                continue;
+            }
+
+            let mut root_children = 0;
+            stack.push((root, usize::MAX, 0));
+
+            while let Some((node, parent_edge, edge_index)) = stack.pop() {
+                if let Some(&(next, id)) = adjacent[node].get(edge_index) {
+                    stack.push((node, parent_edge, edge_index + 1));
+
+                    // skip the edge used to enter node but not its parallel copies
+                    if id == parent_edge {
+                        continue;
+                    }
+
+                    if tin[next] == usize::MAX {
+                        tin[next] = timer;
+                        low[next] = timer;
+                        timer += 1;
+
+                        if node == root {
+                            root_children += 1;
+                        }
+
+                        edge_stack.push(id);
+                        stack.push((next, id, 0));
+                    } else if tin[next] < tin[node] {
+                        // back edge to an ancestor
+                        low[node] = low[node].min(tin[next]);
+                        edge_stack.push(id);
+                    }
+                } else if let Some(&(parent, _, _)) = stack.last() {
+                    // node is finished, propagate to its parent
+                    low[parent] = low[parent].min(low[node]);
+
+                    if low[node] > tin[parent] {
+                        is_bridge[parent_edge] = true;
+                    }
+
+                    if low[node] >= tin[parent] {
+                        if parent != root {
+                            is_articulation[parent] = true;
+                        }
+
+                        // every edge pushed since entering node belongs
+                        // to the block hanging below parent
+                        let mut block = Vec::new();
+                        loop {
+                            let id = edge_stack.pop().unwrap();
+                            block.push(edges[id].0);
+                            block.push(edges[id].1);
+
+                            if id == parent_edge {
+                                break;
+                            }
+                        }
+
+                        block.sort_unstable();
+                        block.dedup();
+                        blocks.push(block);
+                    }
+                }
+            }
+
+            if root_children > 1 {
+                is_articulation[root] = true;
+            }
+        }
+
+        LowLink {
+            n,
+            edges: edges.to_vec(),
+            tin,
+            low,
+            is_bridge,
+            is_articulation,
+            blocks,
+        }
+    }
+
+    pub fn is_bridge(&self, id: usize) -> bool {
+        self.is_bridge[id]
+    }
+
+    // ids of the edges whose removal disconnects their ends
+    pub fn bridges(&self) -> Vec<usize> {
+        (0..self.edges.len())
+            .filter(|&id| self.is_bridge[id])
+            .collect()
+    }
+
+    pub fn is_articulation_point(&self, node: usize) -> bool {
+        self.is_articulation[node]
+    }
+
+    // nodes whose removal increases the number of components
+    pub fn articulation_points(&self) -> Vec<usize> {
+        (0..self.n)
+            .filter(|&node| self.is_articulation[node])
+            .collect()
+    }
+
+    // biconnected components as sorted node sets, articulation points
+    // appear in several of them and isolated nodes form their own
+    pub fn blocks(&self) -> &[Vec<usize>] {
+        &self.blocks
+    }
+
+    // component id of every node once all bridges are removed
+    // and the number of such components
+    pub fn two_edge_connected_components(&self) -> (usize, Vec<usize>) {
+        let mut adjacent = vec![Vec::new(); self.n];
+        for (id, &(a, b)) in self.edges.iter().enumerate() {
+            if !self.is_bridge[id] {
+                adjacent[a].push(b);
+                adjacent[b].push(a);
+            }
+        }
+
+        let mut component = vec![usize::MAX; self.n];
+        let mut count = 0;
+        let mut pending = Vec::new();
+
+        for root in 0..self.n {
+            if component[root] != usize::MAX {
+                continue;
+            }
+
+            component[root] = count;
+            pending.push(root);
+
+            while let Some(node) = pending.pop() {
+                for &next in adjacent[node].iter() {
+                    if component[next] == usize::MAX {
+                        component[next] = count;
+                        pending.push(next);
+                    }
+                }
+            }
+
+            count += 1;
+        }
+
+        (count, component)
+    }
+
+    // tree with a node per block followed by a node per articulation point
+    // and an edge between every articulation point and its blocks
+    pub fn block_cut_tree(&self) -> BlockCutTree {
+        let n_blocks = self.blocks.len();
+        let mut tree_node = vec![usize::MAX; self.n];
+        let mut n_tree_nodes = n_blocks;
+
+        for (node, cut_node) in tree_node.iter_mut().enumerate() {
+            if self.is_articulation[node] {
+                *cut_node = n_tree_nodes;
+                n_tree_nodes += 1;
+            }
+        }
+
+        let mut adjacent = vec![Vec::new(); n_tree_nodes];
+        for (block_id, block) in self.blocks.iter().enumerate() {
+            for &node in block {
+                if self.is_articulation[node] {
+                    adjacent[block_id].push(tree_node[node]);
+                    adjacent[tree_node[node]].push(block_id);
+                } else {
+                    tree_node[node] = block_id;
+                }
+            }
+        }
+
+        BlockCutTree {
+            n_blocks,
+            tree_node,
+            adjacent,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BlockCutTree {
+    // tree nodes below n_blocks are blocks, the rest are articulation points
+    pub n_blocks: usize,
+    // tree node of each graph node, its own node for articulation
+    // points and its only block otherwise
+    pub tree_node: Vec<usize>,
+    pub adjacent: Vec<Vec<usize>>,
+}
@@ -0,0 +1,33 @@
+use cses_rust::coloring::two_coloring;
+use std::io::{stdin, Read};
+
+// https://cses.fi/problemset/task/1668
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut lines = input.lines();
+
+    let mut line = lines.next().unwrap().split_ascii_whitespace();
+    let n_pupils: usize = line.next().unwrap().parse().unwrap();
+    let m_friendships: usize = line.next().unwrap().parse().unwrap();
+
+    let mut adjacent = vec![Vec::new(); n_pupils];
+
+    for _i_conn in 0..m_friendships {
+        let mut line = lines.next().unwrap().split_ascii_whitespace();
+        let a_pupil: usize = line.next().unwrap().parse::<usize>().unwrap() - 1;
+        let b_pupil: usize = line.next().unwrap().parse::<usize>().unwrap() - 1;
+
+        adjacent[a_pupil].push(b_pupil);
+        adjacent[b_pupil].push(a_pupil);
+    }
+
+    // friends must end up in different teams
+    match two_coloring(&adjacent) {
+        Ok(team) => {
+            let output: Vec<String> = team.iter().map(|team| (team + 1).to_string()).collect();
+            println!("{}", output.join(" "));
+        }
+        Err(_) => println!("IMPOSSIBLE"),
+    }
+}
@@ -0,0 +1,36 @@
+use cses_rust::topological::Dag;
+use std::io::{stdin, Read};
+
+// solving the course schedule problem - https://cses.fi/problemset/task/1679
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let first_line: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split(' ')
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_courses = first_line[0];
+    let _m_requirements = first_line[1];
+
+    let mut requirements = vec![Vec::new(); n_courses];
+    for line in input {
+        let values: Vec<usize> = line.split(' ').map(|val| val.parse().unwrap()).collect();
+        requirements[values[0] - 1].push(values[1] - 1);
+    }
+
+    match Dag::new(requirements) {
+        Ok(dag) => {
+            let order: Vec<String> = dag
+                .order()
+                .iter()
+                .map(|node| (node + 1).to_string())
+                .collect();
+            println!("{}", order.join(" "));
+        }
+        Err(_) => println!("IMPOSSIBLE"),
+    }
+}
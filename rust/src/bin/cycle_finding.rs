@@ -0,0 +1,34 @@
+use cses_rust::bellman_ford::{find_negative_cycle, SignedEdge};
+use std::io::{stdin, Read};
+
+// solving the cycle finding problem - https://cses.fi/problemset/task/1197
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let first_line: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split(' ')
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_nodes = first_line[0];
+    let _m_edges = first_line[1];
+
+    let edges: Vec<SignedEdge> = input
+        .map(|line| {
+            let values: Vec<i64> = line.split(' ').map(|val| val.parse().unwrap()).collect();
+            SignedEdge::new(values[0] as usize - 1, values[1] as usize - 1, values[2])
+        })
+        .collect();
+
+    match find_negative_cycle(n_nodes, &edges) {
+        Some(cycle) => {
+            println!("YES");
+            let cycle: Vec<String> = cycle.iter().map(|node| (node + 1).to_string()).collect();
+            println!("{}", cycle.join(" "));
+        }
+        None => println!("NO"),
+    }
+}
@@ -0,0 +1,16 @@
+use cses_rust::euler::de_bruijn;
+use std::io::{stdin, Read};
+
+// solving the de bruijn sequence problem - https://cses.fi/problemset/task/1692
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+
+    let n: usize = input.trim().parse().unwrap();
+
+    let sequence: String = de_bruijn(2, n)
+        .iter()
+        .map(|&bit| if bit == 1 { '1' } else { '0' })
+        .collect();
+    println!("{}", sequence);
+}
@@ -0,0 +1,84 @@
+use cses_rust::{
+    small_to_large::{subtree_answers, SubtreeState},
+    tree::RootedTree,
+};
+use std::io::{stdin, Read};
+
+// node colors compressed to 0..k with the count of each color
+// in the current set and the number of colors present
+struct ColorCounts {
+    color: Vec<usize>,
+    count: Vec<usize>,
+    distinct: usize,
+}
+
+impl SubtreeState for ColorCounts {
+    type Answer = usize;
+
+    fn add(&mut self, node: usize) {
+        let count = &mut self.count[self.color[node]];
+        if *count == 0 {
+            self.distinct += 1;
+        }
+        *count += 1;
+    }
+
+    fn remove(&mut self, node: usize) {
+        let count = &mut self.count[self.color[node]];
+        *count -= 1;
+        if *count == 0 {
+            self.distinct -= 1;
+        }
+    }
+
+    fn answer(&self, _node: usize) -> usize {
+        self.distinct
+    }
+}
+
+// https://cses.fi/problemset/task/1139
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let n_nodes: usize = input.next().unwrap().trim().parse().unwrap();
+
+    let colors: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split_ascii_whitespace()
+        .map(|value| value.parse().unwrap())
+        .collect();
+
+    let edges: Vec<(usize, usize)> = input
+        .take(n_nodes - 1)
+        .map(|line| {
+            let mut edge_line = line.split_ascii_whitespace().take(2);
+            let a = edge_line.next().unwrap().parse::<usize>().unwrap();
+            let b = edge_line.next().unwrap().parse::<usize>().unwrap();
+            (a - 1, b - 1)
+        })
+        .collect();
+
+    // colors go up to 10^9 so they are replaced by their rank
+    let mut sorted_colors = colors.clone();
+    sorted_colors.sort_unstable();
+    sorted_colors.dedup();
+
+    let mut state = ColorCounts {
+        color: colors
+            .iter()
+            .map(|color| sorted_colors.binary_search(color).unwrap())
+            .collect(),
+        count: vec![0; sorted_colors.len()],
+        distinct: 0,
+    };
+
+    let tree = RootedTree::from_edges(n_nodes, &edges, 0);
+    let output: Vec<String> = subtree_answers(&tree, &mut state)
+        .iter()
+        .map(|distinct| distinct.to_string())
+        .collect();
+    println!("{}", output.join(" "));
+}
@@ -0,0 +1,32 @@
+use cses_rust::centroid::CentroidDecomposition;
+use std::io::{stdin, Read};
+
+// https://cses.fi/problemset/task/2081
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let first_line: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split_ascii_whitespace()
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_nodes = first_line[0];
+    let k1 = first_line[1];
+    let k2 = first_line[2];
+
+    let mut adjacent = vec![Vec::new(); n_nodes];
+
+    input.take(n_nodes - 1).for_each(|line| {
+        let mut edge_line = line.split_ascii_whitespace().take(2);
+        let start = edge_line.next().unwrap().parse::<usize>().unwrap() - 1;
+        let end = edge_line.next().unwrap().parse::<usize>().unwrap() - 1;
+        adjacent[start].push(end);
+        adjacent[end].push(start);
+    });
+
+    let decomposition = CentroidDecomposition::new(&adjacent);
+    println!("{}", decomposition.count_paths(k1, k2));
+}
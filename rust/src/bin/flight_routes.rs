@@ -0,0 +1,32 @@
+use cses_rust::{graph::Graph, k_shortest::k_shortest_costs};
+use std::io::{stdin, Read};
+
+// solving the flight routes problem - https://cses.fi/problemset/task/1196
+// routes may visit a city more than once so these are k shortest walks
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let first_line: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split(' ')
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_cities = first_line[0];
+    let _m_flights = first_line[1];
+    let k_routes = first_line[2];
+
+    let mut flight_network = Graph::new(n_cities + 1);
+
+    for line in input {
+        let values: Vec<usize> = line.split(' ').map(|val| val.parse().unwrap()).collect();
+        flight_network.add_edge(values[0], values[1], values[2]);
+    }
+
+    let costs = k_shortest_costs(&flight_network, 1, n_cities, k_routes);
+
+    let costs: Vec<String> = costs.iter().map(|cost| cost.to_string()).collect();
+    println!("{}", costs.join(" "));
+}
@@ -0,0 +1,31 @@
+use cses_rust::topological::Dag;
+use std::io::{stdin, Read};
+
+const MOD: usize = 1_000_000_007;
+
+// solving the game routes problem - https://cses.fi/problemset/task/1681
+// the teleporters are guaranteed to contain no cycles
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let first_line: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split(' ')
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_levels = first_line[0];
+    let _m_teleporters = first_line[1];
+
+    let mut teleporters = vec![Vec::new(); n_levels];
+    for line in input {
+        let values: Vec<usize> = line.split(' ').map(|val| val.parse().unwrap()).collect();
+        teleporters[values[0] - 1].push(values[1] - 1);
+    }
+
+    let dag = Dag::new(teleporters).unwrap();
+
+    println!("{}", dag.count_paths(0, MOD)[n_levels - 1]);
+}
@@ -0,0 +1,28 @@
+use cses_rust::{graph::Graph, hamiltonian::count_paths};
+use std::io::{stdin, Read};
+
+// solving the hamiltonian flights problem - https://cses.fi/problemset/task/1690
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let first_line: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split(' ')
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_cities = first_line[0];
+    let _m_flights = first_line[1];
+
+    let mut flight_network = Graph::new(n_cities);
+
+    for line in input {
+        let values: Vec<usize> = line.split(' ').map(|val| val.parse().unwrap()).collect();
+        flight_network.add_edge(values[0] - 1, values[1] - 1, 1);
+    }
+
+    let modulo = 1_000_000_007;
+    println!("{}", count_paths(&flight_network, 0, n_cities - 1, modulo));
+}
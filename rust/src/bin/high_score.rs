@@ -0,0 +1,35 @@
+use cses_rust::bellman_ford::{bellman_ford, PathCost, SignedEdge};
+use std::io::{stdin, Read};
+
+// solving the high score problem - https://cses.fi/problemset/task/1673
+// maximising the score is minimising its negation, a negative cycle
+// on the way to the last room means the score can grow forever
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let first_line: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split(' ')
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_rooms = first_line[0];
+    let _m_tunnels = first_line[1];
+
+    let tunnels: Vec<SignedEdge> = input
+        .map(|line| {
+            let values: Vec<i64> = line.split(' ').map(|val| val.parse().unwrap()).collect();
+            SignedEdge::new(values[0] as usize - 1, values[1] as usize - 1, -values[2])
+        })
+        .collect();
+
+    let paths = bellman_ford(n_rooms, &tunnels, 0);
+
+    match paths.cost_to(n_rooms - 1) {
+        PathCost::Finite(cost) => println!("{}", -cost),
+        // it is guaranteed that the last room is reachable
+        PathCost::Unbounded | PathCost::Unreachable => println!("-1"),
+    }
+}
@@ -0,0 +1,74 @@
+use cses_rust::{
+    lca::{EulerTourLca, LcaTable},
+    tree::RootedTree,
+};
+use std::{
+    hint::black_box,
+    io::{stdin, Read},
+    time::{Duration, Instant},
+};
+
+// compares LcaTable and EulerTourLca on the same trees and queries
+// input is a single line "n q", run with
+// echo "200000 200000" | cargo run --release --bin lca_benchmark
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let first_line: Vec<usize> = input
+        .split_ascii_whitespace()
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_nodes = first_line[0];
+    let q_queries = first_line[1];
+
+    // fixed seed so every run sees the same trees and queries
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut random = move |bound: usize| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state % bound as u64) as usize
+    };
+
+    let random_parents: Vec<usize> = (0..n_nodes)
+        .map(|node| if node == 0 { usize::MAX } else { random(node) })
+        .collect();
+    let path_parents: Vec<usize> = (0..n_nodes).map(|node| node.wrapping_sub(1)).collect();
+    let queries: Vec<(usize, usize)> = (0..q_queries)
+        .map(|_| (random(n_nodes), random(n_nodes)))
+        .collect();
+
+    for (name, parents) in [("random", random_parents), ("path", path_parents)] {
+        let tree = RootedTree::from_parents(&parents);
+
+        let (lifting, lifting_build) = timed(|| LcaTable::new(&tree));
+        let (euler, euler_build) = timed(|| EulerTourLca::new(&tree));
+
+        let (lifting_sum, lifting_query) = timed(|| {
+            queries
+                .iter()
+                .map(|&(a, b)| black_box(lifting.lca(a, b)))
+                .sum::<usize>()
+        });
+        let (euler_sum, euler_query) = timed(|| {
+            queries
+                .iter()
+                .map(|&(a, b)| black_box(euler.lca(a, b)))
+                .sum::<usize>()
+        });
+
+        // both structures must agree or the timings mean nothing
+        assert_eq!(lifting_sum, euler_sum);
+
+        println!(
+            "{} tree: binary lifting build {:?} queries {:?}, euler tour build {:?} queries {:?}",
+            name, lifting_build, lifting_query, euler_build, euler_query
+        );
+    }
+}
+
+fn timed<T>(run: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = black_box(run());
+    (result, start.elapsed())
+}
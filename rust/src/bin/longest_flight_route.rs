@@ -0,0 +1,36 @@
+use cses_rust::topological::Dag;
+use std::io::{stdin, Read};
+
+// solving the longest flight route problem - https://cses.fi/problemset/task/1680
+// the flights are guaranteed to contain no cycles
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let first_line: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split(' ')
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_cities = first_line[0];
+    let _m_flights = first_line[1];
+
+    let mut flights = vec![Vec::new(); n_cities];
+    for line in input {
+        let values: Vec<usize> = line.split(' ').map(|val| val.parse().unwrap()).collect();
+        flights[values[0] - 1].push(values[1] - 1);
+    }
+
+    let dag = Dag::new(flights).unwrap();
+
+    match dag.longest_path(0, n_cities - 1) {
+        Some(route) => {
+            println!("{}", route.len());
+            let route: Vec<String> = route.iter().map(|node| (node + 1).to_string()).collect();
+            println!("{}", route.join(" "));
+        }
+        None => println!("IMPOSSIBLE"),
+    }
+}
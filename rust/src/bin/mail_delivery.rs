@@ -0,0 +1,34 @@
+use cses_rust::euler::undirected_path;
+use std::io::{stdin, Read};
+
+// solving the mail delivery problem - https://cses.fi/problemset/task/1691
+// an eulerian circuit of the streets starting from the post office
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let first_line: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split(' ')
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_crossings = first_line[0];
+    let _m_streets = first_line[1];
+
+    let streets: Vec<(usize, usize)> = input
+        .map(|line| {
+            let values: Vec<usize> = line.split(' ').map(|val| val.parse().unwrap()).collect();
+            (values[0] - 1, values[1] - 1)
+        })
+        .collect();
+
+    match undirected_path(n_crossings, &streets, 0, 0) {
+        Ok(route) => {
+            let route: Vec<String> = route.iter().map(|node| (node + 1).to_string()).collect();
+            println!("{}", route.join(" "));
+        }
+        Err(_) => println!("IMPOSSIBLE"),
+    }
+}
@@ -0,0 +1,32 @@
+use cses_rust::biconnected::LowLink;
+use std::io::{stdin, Read};
+
+// solving the necessary cities problem - https://cses.fi/problemset/task/2077
+// a city is necessary exactly when it is an articulation point
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let first_line: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split(' ')
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_cities = first_line[0];
+    let _m_roads = first_line[1];
+
+    let roads: Vec<(usize, usize)> = input
+        .map(|line| {
+            let values: Vec<usize> = line.split(' ').map(|val| val.parse().unwrap()).collect();
+            (values[0] - 1, values[1] - 1)
+        })
+        .collect();
+
+    let cities = LowLink::new(n_cities, &roads).articulation_points();
+
+    println!("{}", cities.len());
+    let cities: Vec<String> = cities.iter().map(|city| (city + 1).to_string()).collect();
+    println!("{}", cities.join(" "));
+}
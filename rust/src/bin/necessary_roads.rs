@@ -0,0 +1,35 @@
+use cses_rust::biconnected::LowLink;
+use std::io::{stdin, Read};
+
+// solving the necessary roads problem - https://cses.fi/problemset/task/2076
+// a road is necessary exactly when it is a bridge
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let first_line: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split(' ')
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_cities = first_line[0];
+    let _m_roads = first_line[1];
+
+    let roads: Vec<(usize, usize)> = input
+        .map(|line| {
+            let values: Vec<usize> = line.split(' ').map(|val| val.parse().unwrap()).collect();
+            (values[0] - 1, values[1] - 1)
+        })
+        .collect();
+
+    let bridges = LowLink::new(n_cities, &roads).bridges();
+
+    let mut output = format!("{}\n", bridges.len());
+    for id in bridges {
+        output.push_str(&format!("{} {}\n", roads[id].0 + 1, roads[id].1 + 1));
+    }
+
+    print!("{}", output);
+}
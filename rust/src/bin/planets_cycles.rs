@@ -0,0 +1,27 @@
+use cses_rust::functional_graph::FunctionalGraph;
+use std::io::{stdin, Read};
+
+// https://cses.fi/problemset/task/1751
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let n_planets: usize = input.next().unwrap().trim().parse().unwrap();
+
+    let teleporters: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split(' ')
+        .map(|value| value.parse::<usize>().unwrap() - 1)
+        .collect();
+
+    let graph = FunctionalGraph::new(teleporters);
+
+    // the walk passes every tail planet and every cycle planet once
+    // and the next step repeats the cycle entry
+    let output: Vec<String> = (0..n_planets)
+        .map(|planet| graph.steps_until_repeat(planet).to_string())
+        .collect();
+    println!("{}", output.join(" "));
+}
@@ -0,0 +1,33 @@
+use cses_rust::{graph::Edge, spanning_tree::kruskal};
+use std::io::{stdin, Read};
+
+// solving the road reparation problem - https://cses.fi/problemset/task/1675
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let first_line: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split(' ')
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_cities = first_line[0];
+    let _m_roads = first_line[1];
+
+    let roads: Vec<Edge> = input
+        .map(|line| {
+            let values: Vec<usize> = line.split(' ').map(|val| val.parse().unwrap()).collect();
+            Edge::new(values[0] - 1, values[1] - 1, values[2])
+        })
+        .collect();
+
+    let forest = kruskal(n_cities, &roads);
+
+    if forest.is_spanning_tree() {
+        println!("{}", forest.total_cost);
+    } else {
+        println!("IMPOSSIBLE");
+    }
+}
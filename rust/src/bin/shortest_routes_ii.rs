@@ -0,0 +1,55 @@
+use cses_rust::floyd_warshall::AllPairsShortestPaths;
+use std::io::{stdin, Read};
+
+// solving the shortest routes ii problem - https://cses.fi/problemset/task/1672
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let first_line: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split(' ')
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_cities = first_line[0];
+    let m_roads = first_line[1];
+    let q_queries = first_line[2];
+
+    let mut routes = AllPairsShortestPaths::new(n_cities);
+
+    for _ in 0..m_roads {
+        let values: Vec<u64> = input
+            .next()
+            .unwrap()
+            .split(' ')
+            .map(|val| val.parse().unwrap())
+            .collect();
+        let a_city = values[0] as usize - 1;
+        let b_city = values[1] as usize - 1;
+
+        // roads are two way
+        routes.add_edge(a_city, b_city, values[2]);
+        routes.add_edge(b_city, a_city, values[2]);
+    }
+
+    routes.compute();
+
+    let mut output = String::new();
+    for _ in 0..q_queries {
+        let query: Vec<usize> = input
+            .next()
+            .unwrap()
+            .split(' ')
+            .map(|val| val.parse().unwrap())
+            .collect();
+
+        match routes.dist(query[0] - 1, query[1] - 1) {
+            Some(dist) => output.push_str(&format!("{}\n", dist)),
+            None => output.push_str("-1\n"),
+        }
+    }
+
+    print!("{}", output);
+}
@@ -0,0 +1,54 @@
+use cses_rust::{tree::RootedTree, tree_queries::SubtreeSums};
+use std::io::{stdin, Read};
+
+// https://cses.fi/problemset/task/1137
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let first_line: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split_ascii_whitespace()
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_nodes = first_line[0];
+    let q_queries = first_line[1];
+
+    let values: Vec<i64> = input
+        .next()
+        .unwrap()
+        .split_ascii_whitespace()
+        .map(|value| value.parse().unwrap())
+        .collect();
+
+    let edges: Vec<(usize, usize)> = input
+        .by_ref()
+        .take(n_nodes - 1)
+        .map(|line| {
+            let mut edge_line = line.split_ascii_whitespace().take(2);
+            let a = edge_line.next().unwrap().parse::<usize>().unwrap();
+            let b = edge_line.next().unwrap().parse::<usize>().unwrap();
+            (a - 1, b - 1)
+        })
+        .collect();
+
+    let tree = RootedTree::from_edges(n_nodes, &edges, 0);
+    let mut subtree = SubtreeSums::new(&tree, &values);
+    let mut output = Vec::new();
+
+    for _ in 0..q_queries {
+        let query: Vec<&str> = input.next().unwrap().split_ascii_whitespace().collect();
+        let node = query[1].parse::<usize>().unwrap() - 1;
+
+        match query[0] {
+            // update query
+            "1" => subtree.set(node, query[2].parse().unwrap()),
+            // only other query is the subtree sum
+            _ => output.push(subtree.subtree_sum(node).to_string()),
+        }
+    }
+
+    println!("{}", output.join("\n"));
+}
@@ -0,0 +1,34 @@
+use cses_rust::euler::directed_path;
+use std::io::{stdin, Read};
+
+// solving the teleporters path problem - https://cses.fi/problemset/task/1693
+// an eulerian path of the teleporters from the first to the last level
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let first_line: Vec<usize> = input
+        .next()
+        .unwrap()
+        .split(' ')
+        .map(|val| val.parse().unwrap())
+        .collect();
+    let n_levels = first_line[0];
+    let _m_teleporters = first_line[1];
+
+    let teleporters: Vec<(usize, usize)> = input
+        .map(|line| {
+            let values: Vec<usize> = line.split(' ').map(|val| val.parse().unwrap()).collect();
+            (values[0] - 1, values[1] - 1)
+        })
+        .collect();
+
+    match directed_path(n_levels, &teleporters, 0, n_levels - 1) {
+        Ok(route) => {
+            let route: Vec<String> = route.iter().map(|node| (node + 1).to_string()).collect();
+            println!("{}", route.join(" "));
+        }
+        Err(_) => println!("IMPOSSIBLE"),
+    }
+}
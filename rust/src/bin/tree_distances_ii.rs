@@ -0,0 +1,27 @@
+use cses_rust::{rerooting::distance_sums, tree::RootedTree};
+use std::io::*;
+
+// https://cses.fi/problemset/task/1133
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let n: usize = input.next().unwrap().parse().unwrap();
+    let mut edges = Vec::with_capacity(n - 1);
+
+    for line in input {
+        let edge: Vec<&str> = line.split(' ').collect();
+        let a: usize = edge[0].parse::<usize>().unwrap() - 1;
+        let b: usize = edge[1].parse::<usize>().unwrap() - 1;
+
+        edges.push((a, b));
+    }
+
+    let tree = RootedTree::from_edges(n, &edges, 0);
+    let output: Vec<String> = distance_sums(&tree)
+        .iter()
+        .map(|value| value.to_string())
+        .collect();
+    println!("{}", output.join(" "));
+}
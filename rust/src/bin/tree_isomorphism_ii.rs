@@ -0,0 +1,36 @@
+use cses_rust::isomorphism::unrooted_isomorphic;
+use std::io::{stdin, Read};
+
+// https://cses.fi/problemset/task/1701
+fn main() {
+    let mut input = String::new();
+    stdin().read_to_string(&mut input).unwrap();
+    let mut input = input.lines();
+
+    let t_tests: usize = input.next().unwrap().trim().parse().unwrap();
+    let mut output = Vec::with_capacity(t_tests);
+
+    for _ in 0..t_tests {
+        let n_nodes: usize = input.next().unwrap().trim().parse().unwrap();
+
+        let mut read_edges = || -> Vec<(usize, usize)> {
+            input
+                .by_ref()
+                .take(n_nodes - 1)
+                .map(|line| {
+                    let mut edge_line = line.split_ascii_whitespace().take(2);
+                    let a = edge_line.next().unwrap().parse::<usize>().unwrap();
+                    let b = edge_line.next().unwrap().parse::<usize>().unwrap();
+                    (a - 1, b - 1)
+                })
+                .collect()
+        };
+        let edges_a = read_edges();
+        let edges_b = read_edges();
+
+        let same_shape = unrooted_isomorphic(n_nodes, &edges_a, &edges_b);
+        output.push(if same_shape { "YES" } else { "NO" });
+    }
+
+    println!("{}", output.join("\n"));
+}
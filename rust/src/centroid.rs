@@ -0,0 +1,213 @@
+// centroid decomposition of an unweighted tree given as adjacency lists
+// the centroid of the whole tree is removed, the remaining pieces are
+// decomposed the same way and their centroids hang below it, so the
+// centroid tree has depth O(log n) and every tree path passes through
+// the centroid of the smallest piece containing both ends
+#[derive(Debug, Clone)]
+pub struct CentroidDecomposition {
+    pub root: usize,
+    // parent in the centroid tree, usize::MAX for the root
+    pub parent: Vec<usize>,
+    // depth in the centroid tree
+    pub level: Vec<usize>,
+    // (centroid, distance in the tree) for every centroid whose piece
+    // contains the node, from the root down to the node itself
+    ancestors: Vec<Vec<(usize, usize)>>,
+}
+
+impl CentroidDecomposition {
+    pub fn new(adjacent: &[Vec<usize>]) -> Self {
+        let n = adjacent.len();
+        let mut removed = vec![false; n];
+        let mut parent = vec![usize::MAX; n];
+        let mut level = vec![0; n];
+        let mut ancestors = vec![Vec::new(); n];
+        let mut root = usize::MAX;
+
+        let mut size = vec![1; n];
+        let mut tree_parent = vec![usize::MAX; n];
+        let mut piece = Vec::new();
+
+        // pieces left to decompose as (any node of the piece, centroid above it)
+        // pieces are taken from a stack so the pieces containing a node are
+        // always handled from the largest to the smallest
+        let mut pending = if n > 0 {
+            vec![(0, usize::MAX)]
+        } else {
+            Vec::new()
+        };
+
+        while let Some((start, above)) = pending.pop() {
+            // bfs over the piece, parents come before their children
+            piece.clear();
+            piece.push(start);
+            tree_parent[start] = usize::MAX;
+
+            let mut index = 0;
+            while index < piece.len() {
+                let node = piece[index];
+                index += 1;
+                size[node] = 1;
+
+                for &next in adjacent[node].iter() {
+                    if !removed[next] && next != tree_parent[node] {
+                        tree_parent[next] = node;
+                        piece.push(next);
+                    }
+                }
+            }
+
+            for &node in piece.iter().skip(1).rev() {
+                size[tree_parent[node]] += size[node];
+            }
+
+            // walk into the child holding more than half of the piece
+            let half = piece.len() / 2;
+            let mut centroid = start;
+            while let Some(&heavy_child) = adjacent[centroid]
+                .iter()
+                .find(|&&next| !removed[next] && next != tree_parent[centroid] && size[next] > half)
+            {
+                centroid = heavy_child;
+            }
+
+            // distances from the centroid to the rest of its piece
+            let mut frontier = vec![(centroid, usize::MAX, 0)];
+            while let Some((node, from, dist)) = frontier.pop() {
+                ancestors[node].push((centroid, dist));
+
+                for &next in adjacent[node].iter() {
+                    if !removed[next] && next != from {
+                        frontier.push((next, node, dist + 1));
+                    }
+                }
+            }
+
+            parent[centroid] = above;
+            if above == usize::MAX {
+                root = centroid;
+            } else {
+                level[centroid] = level[above] + 1;
+            }
+
+            removed[centroid] = true;
+            for &next in adjacent[centroid].iter() {
+                if !removed[next] {
+                    pending.push((next, centroid));
+                }
+            }
+        }
+
+        CentroidDecomposition {
+            root,
+            parent,
+            level,
+            ancestors,
+        }
+    }
+
+    // centroids whose piece contains node with their distance to it,
+    // from the root of the centroid tree down to node itself
+    pub fn ancestors(&self, node: usize) -> &[(usize, usize)] {
+        &self.ancestors[node]
+    }
+
+    // number of paths with at least min_len and at most max_len edges
+    // every path is counted at the highest centroid it passes, from the
+    // pairs of its piece in range minus the pairs inside one sub piece
+    pub fn count_paths(&self, min_len: usize, max_len: usize) -> u64 {
+        let n = self.parent.len();
+
+        // node count by distance to each centroid over its piece and
+        // by distance to the parent centroid over the same piece
+        let mut through = vec![Vec::new(); n];
+        let mut from_parent = vec![Vec::new(); n];
+        for ancestors in self.ancestors.iter() {
+            for (i, &(centroid, dist)) in ancestors.iter().enumerate() {
+                add_to_histogram(&mut through[centroid], dist);
+
+                if let Some(&(sub_centroid, _)) = ancestors.get(i + 1) {
+                    add_to_histogram(&mut from_parent[sub_centroid], dist);
+                }
+            }
+        }
+
+        (0..n)
+            .map(|centroid| {
+                pairs_in_range(&through[centroid], min_len, max_len)
+                    - pairs_in_range(&from_parent[centroid], min_len, max_len)
+            })
+            .sum()
+    }
+}
+
+fn add_to_histogram(histogram: &mut Vec<u64>, dist: usize) {
+    if histogram.len() <= dist {
+        histogram.resize(dist + 1, 0);
+    }
+    histogram[dist] += 1;
+}
+
+// unordered pairs of different nodes whose distances add up to a value
+// in min_len..=max_len, given the node count at each distance
+fn pairs_in_range(histogram: &[u64], min_len: usize, max_len: usize) -> u64 {
+    let mut prefix = vec![0; histogram.len() + 1];
+    for (dist, &count) in histogram.iter().enumerate() {
+        prefix[dist + 1] = prefix[dist] + count;
+    }
+
+    // ordered pairs including every node paired with itself
+    let mut ordered = 0;
+    let mut with_itself = 0;
+    for (dist, &count) in histogram.iter().enumerate() {
+        if dist > max_len {
+            break;
+        }
+
+        let low = min_len.saturating_sub(dist).min(histogram.len());
+        let high = (max_len - dist).saturating_add(1).min(histogram.len());
+        if low < high {
+            ordered += count * (prefix[high] - prefix[low]);
+        }
+
+        if min_len <= 2 * dist && 2 * dist <= max_len {
+            with_itself += count;
+        }
+    }
+
+    (ordered - with_itself) / 2
+}
+
+// distance from any node to the nearest marked node, each centroid keeps
+// the closest marked node of its piece so marking and querying walk
+// the O(log n) centroids above a node
+#[derive(Debug, Clone)]
+pub struct NearestMarked<'a> {
+    decomposition: &'a CentroidDecomposition,
+    best: Vec<usize>,
+}
+
+impl<'a> NearestMarked<'a> {
+    pub fn new(decomposition: &'a CentroidDecomposition) -> Self {
+        NearestMarked {
+            decomposition,
+            best: vec![usize::MAX; decomposition.parent.len()],
+        }
+    }
+
+    pub fn mark(&mut self, node: usize) {
+        for &(centroid, dist) in self.decomposition.ancestors(node) {
+            self.best[centroid] = self.best[centroid].min(dist);
+        }
+    }
+
+    // None while no node is marked
+    pub fn nearest(&self, node: usize) -> Option<usize> {
+        self.decomposition
+            .ancestors(node)
+            .iter()
+            .filter(|&&(centroid, _)| self.best[centroid] != usize::MAX)
+            .map(|&(centroid, dist)| dist + self.best[centroid])
+            .min()
+    }
+}
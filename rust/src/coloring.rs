@@ -0,0 +1,207 @@
+use std::collections::VecDeque;
+
+// vertex colorings of undirected graphs given as adjacency lists that
+// list each edge from both ends, colors are numbered from 0
+
+// two coloring found with a bfs per component, fails with an odd
+// cycle in walking order where the last node has an edge back to the first
+// a same colored edge (a, b) joins two nodes of equal bfs depth so the
+// tree paths from both up to their common ancestor close an odd cycle
+pub fn two_coloring(adjacent: &[Vec<usize>]) -> Result<Vec<usize>, Vec<usize>> {
+    let n = adjacent.len();
+    let mut color = vec![usize::MAX; n];
+    let mut parent = vec![usize::MAX; n];
+    let mut queue = VecDeque::new();
+
+    for root in 0..n {
+        if color[root] != usize::MAX {
+            continue;
+        }
+
+        color[root] = 0;
+        queue.push_back(root);
+
+        while let Some(node) = queue.pop_front() {
+            for &next in adjacent[node].iter() {
+                if color[next] == usize::MAX {
+                    color[next] = 1 - color[node];
+                    parent[next] = node;
+                    queue.push_back(next);
+                } else if color[next] == color[node] {
+                    return Err(odd_cycle(&parent, node, next));
+                }
+            }
+        }
+    }
+
+    Ok(color)
+}
+
+// climbs from both ends of a same colored edge in lockstep
+// until the paths meet, a self loop is a cycle of one node
+fn odd_cycle(parent: &[usize], a: usize, b: usize) -> Vec<usize> {
+    let mut from_a = vec![a];
+    let mut from_b = vec![b];
+    let (mut cur_a, mut cur_b) = (a, b);
+
+    while cur_a != cur_b {
+        cur_a = parent[cur_a];
+        cur_b = parent[cur_b];
+        from_a.push(cur_a);
+        from_b.push(cur_b);
+    }
+
+    // the common ancestor ends both paths, keep it once
+    from_b.pop();
+    from_b.reverse();
+    from_a.extend(from_b);
+    from_a
+}
+
+// colors nodes in the given order with the smallest color
+// not used by an already colored neighbour, self loops are ignored
+pub fn greedy_coloring(adjacent: &[Vec<usize>], order: &[usize]) -> Vec<usize> {
+    let mut color = vec![usize::MAX; adjacent.len()];
+    let mut taken = vec![false; adjacent.len() + 1];
+
+    for &node in order {
+        for &next in adjacent[node].iter() {
+            if color[next] != usize::MAX {
+                taken[color[next]] = true;
+            }
+        }
+
+        color[node] = taken.iter().position(|&taken| !taken).unwrap();
+
+        for &next in adjacent[node].iter() {
+            if color[next] != usize::MAX {
+                taken[color[next]] = false;
+            }
+        }
+    }
+
+    color
+}
+
+// number of distinct colors of the colored neighbours of every node
+// and whether a color is present among them
+struct Saturation {
+    seen: Vec<Vec<bool>>,
+    degree: Vec<usize>,
+}
+
+impl Saturation {
+    fn new(n: usize) -> Self {
+        Saturation {
+            seen: vec![vec![false; n + 1]; n],
+            degree: vec![0; n],
+        }
+    }
+
+    fn add(&mut self, node: usize, color: usize) {
+        if !self.seen[node][color] {
+            self.seen[node][color] = true;
+            self.degree[node] += 1;
+        }
+    }
+
+    // uncolored node with the most distinct neighbour colors,
+    // ties broken by the larger degree
+    fn most_saturated(&self, adjacent: &[Vec<usize>], color: &[usize]) -> Option<usize> {
+        (0..adjacent.len())
+            .filter(|&node| color[node] == usize::MAX)
+            .max_by_key(|&node| (self.degree[node], adjacent[node].len()))
+    }
+}
+
+// dsatur heuristic, repeatedly colors the most saturated node with the
+// smallest free color, O(n^2 + m) so meant for small graphs
+// exact on bipartite graphs, self loops are ignored
+pub fn dsatur_coloring(adjacent: &[Vec<usize>]) -> Vec<usize> {
+    let n = adjacent.len();
+    let mut color = vec![usize::MAX; n];
+    let mut saturation = Saturation::new(n);
+
+    while let Some(node) = saturation.most_saturated(adjacent, &color) {
+        color[node] = saturation.seen[node]
+            .iter()
+            .position(|&seen| !seen)
+            .unwrap();
+
+        for &next in adjacent[node].iter() {
+            saturation.add(next, color[node]);
+        }
+    }
+
+    color
+}
+
+// coloring with at most k colors if one exists, an exponential
+// backtracking search that branches on the most saturated node first
+pub fn k_coloring(adjacent: &[Vec<usize>], k: usize) -> Option<Vec<usize>> {
+    let n = adjacent.len();
+    if (0..n).any(|node| adjacent[node].contains(&node)) {
+        return None;
+    }
+
+    // uses at most the largest color of the dsatur heuristic plus one
+    let heuristic = dsatur_coloring(adjacent);
+    if heuristic.iter().all(|&color| color < k) {
+        return Some(heuristic);
+    }
+
+    let mut color = vec![usize::MAX; n];
+    if extend_coloring(adjacent, k, &mut color) {
+        Some(color)
+    } else {
+        None
+    }
+}
+
+// tries every color for the most constrained uncolored node,
+// a node with no free color fails the branch straight away
+fn extend_coloring(adjacent: &[Vec<usize>], k: usize, color: &mut [usize]) -> bool {
+    let mut best: Option<(usize, usize)> = None;
+    let mut taken = vec![false; k];
+    for node in 0..adjacent.len() {
+        if color[node] != usize::MAX {
+            continue;
+        }
+
+        taken.iter_mut().for_each(|taken| *taken = false);
+        for &next in adjacent[node].iter() {
+            if color[next] != usize::MAX {
+                taken[color[next]] = true;
+            }
+        }
+
+        let saturation = taken.iter().filter(|&&taken| taken).count();
+        if best.is_none_or(|(_, best_saturation)| saturation > best_saturation) {
+            best = Some((node, saturation));
+        }
+    }
+
+    let node = match best {
+        Some((node, _)) => node,
+        None => return true,
+    };
+
+    for candidate in 0..k {
+        if adjacent[node].iter().any(|&next| color[next] == candidate) {
+            continue;
+        }
+
+        color[node] = candidate;
+        if extend_coloring(adjacent, k, color) {
+            return true;
+        }
+    }
+
+    color[node] = usize::MAX;
+    false
+}
+
+// smallest k for which k_coloring succeeds, exponential
+pub fn chromatic_number(adjacent: &[Vec<usize>]) -> Option<usize> {
+    (0..=adjacent.len()).find(|&k| k_coloring(adjacent, k).is_some())
+}
@@ -0,0 +1,259 @@
+use std::collections::VecDeque;
+
+// cycle search over adjacency lists, undirected graphs list each edge
+// from both ends, in undirected graphs parallel edges and self loops
+// are not treated as cycles
+// cycles are returned as nodes in walking order without repeating
+// the first node, so the last node has an edge back to the first
+
+// any cycle of an undirected graph using an iterative dfs
+// a non tree edge in an undirected dfs always leads to an ancestor
+// so the cycle is the part of the dfs path from that ancestor
+pub fn find_undirected_cycle(adjacent: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let n = adjacent.len();
+    let mut visited = vec![false; n];
+    let mut position = vec![usize::MAX; n];
+    // current dfs path as (node, parent, next edge index)
+    let mut path: Vec<(usize, usize, usize)> = Vec::new();
+
+    for root in 0..n {
+        if visited[root] {
+            continue;
+        }
+
+        visited[root] = true;
+        position[root] = 0;
+        path.push((root, usize::MAX, 0));
+
+        while let Some(&mut (node, parent, ref mut edge_index)) = path.last_mut() {
+            match adjacent[node].get(*edge_index) {
+                Some(&next) => {
+                    *edge_index += 1;
+
+                    // skip immediate loop back
+                    if next == parent || next == node {
+                        continue;
+                    }
+
+                    // a finished node was reached over a parallel edge
+                    // to one of its children, which is not a cycle
+                    if visited[next] {
+                        if position[next] == usize::MAX {
+                            continue;
+                        }
+
+                        return Some(path[position[next]..].iter().map(|frame| frame.0).collect());
+                    }
+
+                    visited[next] = true;
+                    position[next] = path.len();
+                    path.push((next, node, 0));
+                }
+                None => {
+                    position[node] = usize::MAX;
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// any cycle of a directed graph using an iterative dfs
+// an edge into a node still on the dfs path closes a cycle
+pub fn find_directed_cycle(adjacent: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let n = adjacent.len();
+    let mut visited = vec![false; n];
+    // index of a node on the current dfs path, usize::MAX when off it
+    let mut position = vec![usize::MAX; n];
+    let mut path: Vec<(usize, usize)> = Vec::new();
+
+    for root in 0..n {
+        if visited[root] {
+            continue;
+        }
+
+        visited[root] = true;
+        position[root] = 0;
+        path.push((root, 0));
+
+        while let Some(&mut (node, ref mut edge_index)) = path.last_mut() {
+            match adjacent[node].get(*edge_index) {
+                Some(&next) => {
+                    *edge_index += 1;
+
+                    if position[next] != usize::MAX {
+                        return Some(path[position[next]..].iter().map(|frame| frame.0).collect());
+                    }
+
+                    // node has been visited before in a previous trip
+                    if visited[next] {
+                        continue;
+                    }
+
+                    visited[next] = true;
+                    position[next] = path.len();
+                    path.push((next, 0));
+                }
+                None => {
+                    position[node] = usize::MAX;
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// bfs distances and parents from source
+fn bfs(adjacent: &[Vec<usize>], source: usize) -> (Vec<usize>, Vec<usize>) {
+    let mut dist = vec![usize::MAX; adjacent.len()];
+    let mut parent = vec![usize::MAX; adjacent.len()];
+    let mut queue = VecDeque::new();
+
+    dist[source] = 0;
+    queue.push_back(source);
+
+    while let Some(node) = queue.pop_front() {
+        for &next in adjacent[node].iter() {
+            if dist[next] == usize::MAX {
+                dist[next] = dist[node] + 1;
+                parent[next] = node;
+                queue.push_back(next);
+            }
+        }
+    }
+
+    (dist, parent)
+}
+
+// nodes on the bfs tree path from the source to node, both inclusive
+fn tree_path(parent: &[usize], node: usize) -> Vec<usize> {
+    let mut path = vec![node];
+    let mut cur = node;
+    while parent[cur] != usize::MAX {
+        cur = parent[cur];
+        path.push(cur);
+    }
+
+    path.reverse();
+    path
+}
+
+// shortest cycle through source in an undirected graph
+// nodes are labelled by the child of source their bfs path starts with,
+// a non tree edge joining two different labels closes a cycle through
+// source whose length is the sum of both depths plus one
+pub fn shortest_undirected_cycle_through(
+    adjacent: &[Vec<usize>],
+    source: usize,
+) -> Option<Vec<usize>> {
+    let (dist, parent) = bfs(adjacent, source);
+
+    let mut branch = vec![usize::MAX; adjacent.len()];
+    branch[source] = source;
+    let mut by_depth: Vec<usize> = (0..adjacent.len())
+        .filter(|&node| dist[node] != usize::MAX)
+        .collect();
+    by_depth.sort_by_key(|&node| dist[node]);
+    for &node in by_depth.iter().skip(1) {
+        branch[node] = if parent[node] == source {
+            node
+        } else {
+            branch[parent[node]]
+        };
+    }
+
+    let mut best: Option<(usize, usize, usize)> = None;
+    for &node in by_depth.iter() {
+        for &next in adjacent[node].iter() {
+            // tree edges and edges inside one branch do not pass source
+            if next == node
+                || parent[next] == node
+                || parent[node] == next
+                || branch[node] == branch[next]
+            {
+                continue;
+            }
+
+            let length = dist[node] + dist[next] + 1;
+            if best.is_none_or(|(best_length, _, _)| length < best_length) {
+                best = Some((length, node, next));
+            }
+        }
+    }
+
+    let (_, node, next) = best?;
+    let mut cycle = tree_path(&parent, node);
+    let mut back = tree_path(&parent, next);
+    back.reverse();
+    // back ends with source which already starts the cycle
+    back.pop();
+    cycle.extend(back);
+
+    Some(cycle)
+}
+
+// shortest cycle through source in a directed graph, the bfs
+// path to the closest node with an edge back to source
+pub fn shortest_directed_cycle_through(
+    adjacent: &[Vec<usize>],
+    source: usize,
+) -> Option<Vec<usize>> {
+    let (dist, parent) = bfs(adjacent, source);
+
+    let closing = (0..adjacent.len())
+        .filter(|&node| dist[node] != usize::MAX && adjacent[node].contains(&source))
+        .min_by_key(|&node| dist[node])?;
+
+    Some(tree_path(&parent, closing))
+}
+
+// shortest cycle of an undirected graph, O(n m)
+// from the best root any non tree edge (a, b) closes a cycle of
+// length dist[a] + dist[b] + 1, the two tree paths may overlap for
+// other roots but then a shorter cycle exists, so the minimum over
+// all roots is exact
+pub fn undirected_girth(adjacent: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let mut best: Option<(usize, usize, usize, usize)> = None;
+
+    for root in 0..adjacent.len() {
+        let (dist, parent) = bfs(adjacent, root);
+
+        for node in 0..adjacent.len() {
+            if dist[node] == usize::MAX {
+                continue;
+            }
+
+            for &next in adjacent[node].iter() {
+                if next == node || parent[next] == node || parent[node] == next {
+                    continue;
+                }
+
+                let length = dist[node] + dist[next] + 1;
+                if best.is_none_or(|(best_length, _, _, _)| length < best_length) {
+                    best = Some((length, root, node, next));
+                }
+            }
+        }
+    }
+
+    let (_, root, node, next) = best?;
+    let (_, parent) = bfs(adjacent, root);
+    let mut cycle = tree_path(&parent, node);
+    let mut back = tree_path(&parent, next);
+    back.reverse();
+    back.pop();
+    cycle.extend(back);
+
+    Some(cycle)
+}
+
+// shortest cycle of a directed graph, O(n m)
+pub fn directed_girth(adjacent: &[Vec<usize>]) -> Option<Vec<usize>> {
+    (0..adjacent.len())
+        .filter_map(|source| shortest_directed_cycle_through(adjacent, source))
+        .min_by_key(|cycle| cycle.len())
+}
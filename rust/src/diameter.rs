@@ -0,0 +1,97 @@
+// diameter of a tree with weighted edges given as (a, b, length)
+// the farthest node from any node is an end of some diameter, so a
+// pass from node 0 finds one end, a pass from that end finds the other
+// and a third pass from the other end gives every node its distance
+// to both ends, the larger of which is its farthest distance
+#[derive(Debug, Clone)]
+pub struct TreeDiameter {
+    pub length: u64,
+    pub ends: (usize, usize),
+    // nodes of the diameter from ends.0 to ends.1
+    pub path: Vec<usize>,
+    from_start: Vec<u64>,
+    from_end: Vec<u64>,
+}
+
+impl TreeDiameter {
+    pub fn new(n: usize, edges: &[(usize, usize, u64)]) -> Self {
+        let mut adjacent = vec![Vec::new(); n];
+        for &(a, b, length) in edges {
+            adjacent[a].push((b, length));
+            adjacent[b].push((a, length));
+        }
+
+        let farthest = |dist: &[u64]| (0..n).max_by_key(|&node| dist[node]).unwrap();
+
+        let (from_any, _) = distances(&adjacent, 0);
+        let start = farthest(&from_any);
+        let (from_start, parent) = distances(&adjacent, start);
+        let end = farthest(&from_start);
+        let (from_end, _) = distances(&adjacent, end);
+
+        let mut path = vec![end];
+        let mut node = end;
+        while node != start {
+            node = parent[node];
+            path.push(node);
+        }
+        path.reverse();
+
+        TreeDiameter {
+            length: from_start[end],
+            ends: (start, end),
+            path,
+            from_start,
+            from_end,
+        }
+    }
+
+    // distance from node to the node farthest from it
+    pub fn eccentricity(&self, node: usize) -> u64 {
+        self.from_start[node].max(self.from_end[node])
+    }
+
+    pub fn farthest_distances(&self) -> Vec<u64> {
+        (0..self.from_start.len())
+            .map(|node| self.eccentricity(node))
+            .collect()
+    }
+
+    // nodes of the diameter path with the smallest eccentricity, a center
+    // always lies on the diameter so these are the centers of the tree
+    // up to ties through zero length edges
+    pub fn centers(&self) -> Vec<usize> {
+        let radius = self
+            .path
+            .iter()
+            .map(|&node| self.eccentricity(node))
+            .min()
+            .unwrap();
+
+        self.path
+            .iter()
+            .copied()
+            .filter(|&node| self.eccentricity(node) == radius)
+            .collect()
+    }
+}
+
+// distance and parent of every node seen from source, walked with an
+// explicit stack so path shaped trees do not overflow the stack
+fn distances(adjacent: &[Vec<(usize, u64)>], source: usize) -> (Vec<u64>, Vec<usize>) {
+    let mut dist = vec![0; adjacent.len()];
+    let mut parent = vec![usize::MAX; adjacent.len()];
+    let mut pending = vec![source];
+
+    while let Some(node) = pending.pop() {
+        for &(next, length) in adjacent[node].iter() {
+            if next != parent[node] {
+                parent[next] = node;
+                dist[next] = dist[node] + length;
+                pending.push(next);
+            }
+        }
+    }
+
+    (dist, parent)
+}
@@ -0,0 +1,136 @@
+use crate::graph::Graph;
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+// a node paired with a small amount of extra state, e.g. coupons used,
+// path parity or fuel left, for searches over a product graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Layered {
+    pub node: usize,
+    pub layer: usize,
+}
+
+impl Layered {
+    pub fn new(node: usize, layer: usize) -> Self {
+        Layered { node, layer }
+    }
+
+    // index into a table with n_nodes entries per layer
+    pub fn index(self, n_nodes: usize) -> usize {
+        self.layer * n_nodes + self.node
+    }
+}
+
+// result of a search, costs and parents are indexed by state index
+// usize::MAX cost means the state was not reached
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S> {
+    cost: Vec<usize>,
+    // index of the state each state was last relaxed from
+    parent: Vec<usize>,
+    state: Vec<Option<S>>,
+}
+
+impl<S: Copy> ShortestPaths<S> {
+    pub fn cost(&self, index: usize) -> Option<usize> {
+        match self.cost[index] {
+            usize::MAX => None,
+            cost => Some(cost),
+        }
+    }
+
+    // raw cost table, usize::MAX for unreached states
+    pub fn costs(&self) -> &[usize] {
+        &self.cost
+    }
+
+    // state from which index was last relaxed, None for sources
+    // and unreached states
+    pub fn parent(&self, index: usize) -> Option<S> {
+        self.state.get(self.parent[index]).copied().flatten()
+    }
+
+    // states from a source to the state at index, both inclusive
+    pub fn path(&self, index: usize) -> Option<Vec<S>> {
+        let mut path = vec![self.state[index]?];
+        let mut cur = index;
+
+        while self.parent[cur] != usize::MAX {
+            cur = self.parent[cur];
+            path.push(self.state[cur].unwrap());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+// dijkstra over an implicit graph of states
+// n_states bounds the state indices returned by state_index
+// transitions is called once per settled state and should push
+// every (next state, cost) reachable from it into the buffer
+// the heap is not decreased in place, older more costly entries
+// for a state are skipped when popped
+pub fn dijkstra<S, I, T>(
+    n_states: usize,
+    sources: &[S],
+    state_index: I,
+    mut transitions: T,
+) -> ShortestPaths<S>
+where
+    S: Copy,
+    I: Fn(S) -> usize,
+    T: FnMut(S, &mut Vec<(S, usize)>),
+{
+    let mut paths = ShortestPaths {
+        cost: vec![usize::MAX; n_states],
+        parent: vec![usize::MAX; n_states],
+        state: vec![None; n_states],
+    };
+
+    let mut next_visit_min_heap = BinaryHeap::new();
+    for &source in sources {
+        let index = state_index(source);
+        paths.cost[index] = 0;
+        paths.state[index] = Some(source);
+        next_visit_min_heap.push(Reverse((0, index)));
+    }
+
+    let mut next_states = Vec::new();
+
+    while let Some(Reverse((cost, index))) = next_visit_min_heap.pop() {
+        // stale entry, state was settled with a lower cost
+        if cost > paths.cost[index] {
+            continue;
+        }
+
+        let state = paths.state[index].unwrap();
+        next_states.clear();
+        transitions(state, &mut next_states);
+
+        for &(next_state, edge_cost) in next_states.iter() {
+            let next_index = state_index(next_state);
+            let next_cost = cost + edge_cost;
+
+            if next_cost < paths.cost[next_index] {
+                paths.cost[next_index] = next_cost;
+                paths.parent[next_index] = index;
+                paths.state[next_index] = Some(next_state);
+                next_visit_min_heap.push(Reverse((next_cost, next_index)));
+            }
+        }
+    }
+
+    paths
+}
+
+// plain single source dijkstra where the state is the node itself
+pub fn shortest_paths(graph: &Graph, source: usize) -> ShortestPaths<usize> {
+    dijkstra(
+        graph.n,
+        &[source],
+        |node| node,
+        |node, next| {
+            next.extend(graph.edges[node].iter().map(|edge| (edge.end, edge.cost)));
+        },
+    )
+}
@@ -0,0 +1,188 @@
+use crate::union_find::UnionFind;
+use std::{collections::HashMap, mem::take};
+
+// undirected edges are keyed with the smaller node first
+fn edge_key(a: usize, b: usize) -> (usize, usize) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+// number of components after each deletion, given the initial
+// edges and the full deletion sequence up front
+// deleting is hard for union find but adding is easy, so start from
+// the graph left after all deletions and add them back in reverse
+// O((m + k) α(n)) instead of a search per deletion
+pub fn components_after_deletions(
+    n: usize,
+    edges: &[(usize, usize)],
+    deletions: &[(usize, usize)],
+) -> Vec<usize> {
+    // parallel edges are counted so only one copy is removed per deletion
+    let mut remaining: HashMap<(usize, usize), usize> = HashMap::new();
+    for &(a, b) in edges {
+        *remaining.entry(edge_key(a, b)).or_insert(0) += 1;
+    }
+
+    for &(a, b) in deletions {
+        *remaining.get_mut(&edge_key(a, b)).unwrap() -= 1;
+    }
+
+    let mut uf = UnionFind::new(n);
+    for (&(a, b), &count) in remaining.iter() {
+        if count > 0 {
+            uf.create_union(a, b);
+        }
+    }
+
+    let mut components = vec![0; deletions.len()];
+    for (i, &(a, b)) in deletions.iter().enumerate().rev() {
+        components[i] = uf.components;
+        uf.create_union(a, b);
+    }
+
+    components
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Query {
+    Components,
+    Connected(usize, usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+    Components(usize),
+    Connected(bool),
+}
+
+// offline connectivity under interleaved edge additions and deletions
+// every edge is alive over an interval of queries, the intervals are
+// stored on a segment tree over query time and a depth first walk
+// of the tree applies them on a rollback union find
+// O((m log q + q) log n) overall
+// reference - https://cp-algorithms.com/data_structures/deleting_in_log_n.html
+#[derive(Debug, Clone)]
+pub struct OfflineConnectivity {
+    n: usize,
+    // times at which currently alive copies of an edge were added
+    alive: HashMap<(usize, usize), Vec<usize>>,
+    // (first query, last query exclusive, edge) for each edge lifetime
+    lifetimes: Vec<(usize, usize, (usize, usize))>,
+    queries: Vec<Query>,
+}
+
+impl OfflineConnectivity {
+    pub fn new(n: usize) -> Self {
+        OfflineConnectivity {
+            n,
+            alive: HashMap::new(),
+            lifetimes: Vec::new(),
+            queries: Vec::new(),
+        }
+    }
+
+    pub fn add_edge(&mut self, a: usize, b: usize) {
+        self.alive
+            .entry(edge_key(a, b))
+            .or_default()
+            .push(self.queries.len());
+    }
+
+    // removes one alive copy of the edge a - b
+    pub fn remove_edge(&mut self, a: usize, b: usize) {
+        let key = edge_key(a, b);
+        let added = self.alive.get_mut(&key).unwrap().pop().unwrap();
+
+        if added < self.queries.len() {
+            self.lifetimes.push((added, self.queries.len(), key));
+        }
+    }
+
+    pub fn query(&mut self, query: Query) {
+        self.queries.push(query);
+    }
+
+    // answers for every query in the order they were asked
+    pub fn solve(mut self) -> Vec<Answer> {
+        let q = self.queries.len();
+        if q == 0 {
+            return Vec::new();
+        }
+
+        // edges never removed live until the last query
+        for (key, added_times) in take(&mut self.alive) {
+            for added in added_times {
+                if added < q {
+                    self.lifetimes.push((added, q, key));
+                }
+            }
+        }
+
+        let mut segments: Vec<Vec<(usize, usize)>> = vec![Vec::new(); 4 * q];
+        for &(start, end, edge) in self.lifetimes.iter() {
+            OfflineConnectivity::insert(&mut segments, 1, 0, q, start, end, edge);
+        }
+
+        let mut uf = UnionFind::new(self.n).with_rollback();
+        let mut answers = Vec::with_capacity(q);
+        self.walk(&segments, &mut uf, 1, 0, q, &mut answers);
+
+        answers
+    }
+
+    // store edge on the nodes covering [start, end) of node range [l, r)
+    fn insert(
+        segments: &mut [Vec<(usize, usize)>],
+        node: usize,
+        l: usize,
+        r: usize,
+        start: usize,
+        end: usize,
+        edge: (usize, usize),
+    ) {
+        if end <= l || r <= start {
+            return;
+        }
+
+        if start <= l && r <= end {
+            segments[node].push(edge);
+            return;
+        }
+
+        let mid = (l + r) / 2;
+        OfflineConnectivity::insert(segments, 2 * node, l, mid, start, end, edge);
+        OfflineConnectivity::insert(segments, 2 * node + 1, mid, r, start, end, edge);
+    }
+
+    // recursion depth is only log q
+    fn walk(
+        &self,
+        segments: &[Vec<(usize, usize)>],
+        uf: &mut UnionFind,
+        node: usize,
+        l: usize,
+        r: usize,
+        answers: &mut Vec<Answer>,
+    ) {
+        let snapshot = uf.snapshot();
+        for &(a, b) in segments[node].iter() {
+            uf.create_union(a, b);
+        }
+
+        if r - l == 1 {
+            answers.push(match self.queries[l] {
+                Query::Components => Answer::Components(uf.components),
+                Query::Connected(a, b) => Answer::Connected(uf.connected(a, b)),
+            });
+        } else {
+            let mid = (l + r) / 2;
+            self.walk(segments, uf, 2 * node, l, mid, answers);
+            self.walk(segments, uf, 2 * node + 1, mid, r, answers);
+        }
+
+        uf.rollback(snapshot);
+    }
+}
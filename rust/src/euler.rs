@@ -0,0 +1,184 @@
+// eulerian paths and circuits with hierholzer's algorithm
+// graphs are edge lists of node pairs so undirected edges have a
+// single id shared by both directions
+// reference - https://cp-algorithms.com/graph/euler_path.html
+
+// reason an eulerian path does not exist
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EulerError {
+    // undirected nodes with odd degree other than the requested ends
+    OddDegree(Vec<usize>),
+    // directed node whose out degree minus in degree is not the
+    // required 1 for the start, -1 for the end and 0 otherwise
+    Imbalance {
+        node: usize,
+        out_degree: usize,
+        in_degree: usize,
+    },
+    // an edge that cannot be reached from the start
+    Disconnected {
+        edge: usize,
+    },
+}
+
+// walk starting at start using every undirected edge exactly once
+// and ending at end, start == end asks for a circuit
+pub fn undirected_path(
+    n: usize,
+    edges: &[(usize, usize)],
+    start: usize,
+    end: usize,
+) -> Result<Vec<usize>, EulerError> {
+    let mut degree = vec![0; n];
+    for &(a, b) in edges {
+        degree[a] += 1;
+        degree[b] += 1;
+    }
+
+    // the ends of an open path are the only nodes with odd degree
+    let odd: Vec<usize> = (0..n)
+        .filter(|&node| {
+            let is_end = start != end && (node == start || node == end);
+            (degree[node] % 2 == 1) != is_end
+        })
+        .collect();
+    if !odd.is_empty() {
+        return Err(EulerError::OddDegree(odd));
+    }
+
+    let mut adjacent = vec![Vec::new(); n];
+    for (id, &(a, b)) in edges.iter().enumerate() {
+        adjacent[a].push((b, id));
+        adjacent[b].push((a, id));
+    }
+
+    let mut used = vec![false; edges.len()];
+    let path = hierholzer(n, start, edges.len(), |node, next_index| {
+        // skip edges already walked from the other side
+        while let Some(&(next, id)) = adjacent[node].get(*next_index) {
+            *next_index += 1;
+
+            if !used[id] {
+                used[id] = true;
+                return Some(next);
+            }
+        }
+
+        None
+    });
+
+    check_all_used(&used)?;
+    Ok(path)
+}
+
+// walk starting at start using every directed edge exactly once
+// and ending at end, start == end asks for a circuit
+pub fn directed_path(
+    n: usize,
+    edges: &[(usize, usize)],
+    start: usize,
+    end: usize,
+) -> Result<Vec<usize>, EulerError> {
+    let mut out_degree = vec![0; n];
+    let mut in_degree = vec![0; n];
+    for &(a, b) in edges {
+        out_degree[a] += 1;
+        in_degree[b] += 1;
+    }
+
+    for node in 0..n {
+        let balance = out_degree[node] as i64 - in_degree[node] as i64;
+        let expected = (node == start) as i64 - (node == end) as i64;
+
+        if balance != expected {
+            return Err(EulerError::Imbalance {
+                node,
+                out_degree: out_degree[node],
+                in_degree: in_degree[node],
+            });
+        }
+    }
+
+    let mut adjacent = vec![Vec::new(); n];
+    for (id, &(a, b)) in edges.iter().enumerate() {
+        adjacent[a].push((b, id));
+    }
+
+    let mut used = vec![false; edges.len()];
+    let path = hierholzer(n, start, edges.len(), |node, next_index| {
+        let &(next, id) = adjacent[node].get(*next_index)?;
+        *next_index += 1;
+        used[id] = true;
+        Some(next)
+    });
+
+    check_all_used(&used)?;
+    Ok(path)
+}
+
+// iterative hierholzer, next_edge takes a node with its cursor into the
+// adjacency list and returns the end of an unused edge if one is left
+// nodes are emitted when they run out of edges so the emitted order
+// is the reversed walk
+fn hierholzer<F>(n: usize, start: usize, n_edges: usize, mut next_edge: F) -> Vec<usize>
+where
+    F: FnMut(usize, &mut usize) -> Option<usize>,
+{
+    let mut cursor = vec![0; n];
+    let mut stack = vec![start];
+    let mut path = Vec::with_capacity(n_edges + 1);
+
+    while let Some(&node) = stack.last() {
+        match next_edge(node, &mut cursor[node]) {
+            Some(next) => stack.push(next),
+            None => {
+                path.push(node);
+                stack.pop();
+            }
+        }
+    }
+
+    path.reverse();
+    path
+}
+
+// the degree checks pass for graphs split into several parts, any
+// edge left unused lies outside the part containing the start
+fn check_all_used(used: &[bool]) -> Result<(), EulerError> {
+    match used.iter().position(|&used| !used) {
+        Some(edge) => Err(EulerError::Disconnected { edge }),
+        None => Ok(()),
+    }
+}
+
+// shortest sequence over the alphabet 0..k containing every word of
+// length n as a contiguous block, it has length k^n + n - 1
+// words of length n - 1 are nodes and each word of length n is the
+// edge from its prefix to its suffix, so an eulerian circuit of this
+// graph visits every word once
+pub fn de_bruijn(k: usize, n: usize) -> Vec<usize> {
+    // the empty word is in every sequence and an empty alphabet has no
+    // words, so neither has a graph to walk
+    if n == 0 || k == 0 {
+        return Vec::new();
+    }
+    if n == 1 {
+        return (0..k).collect();
+    }
+
+    let n_nodes = k.pow(n as u32 - 1);
+    let mut edges = Vec::with_capacity(n_nodes * k);
+    for node in 0..n_nodes {
+        for symbol in 0..k {
+            edges.push((node, (node * k + symbol) % n_nodes));
+        }
+    }
+
+    let circuit = directed_path(n_nodes, &edges, 0, 0).unwrap();
+
+    // the start node spells the first n - 1 symbols and every
+    // step appends the last symbol of the next node
+    let mut sequence = vec![0; n - 1];
+    sequence.extend(circuit.iter().skip(1).map(|node| node % k));
+    sequence
+}
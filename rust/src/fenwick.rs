@@ -0,0 +1,65 @@
+// fenwick tree over signed sums, index i covers the i & -i values
+// ending at it in the 1 indexed internal array
+// reference - https://cp-algorithms.com/data_structures/fenwick.html
+#[derive(Debug, Clone)]
+pub struct Fenwick {
+    tree: Vec<i64>,
+}
+
+impl Fenwick {
+    pub fn new(n: usize) -> Self {
+        Fenwick {
+            tree: vec![0; n + 1],
+        }
+    }
+
+    // linear construction, every node pushes its total to the
+    // next node whose range contains it
+    pub fn from_values(values: &[i64]) -> Self {
+        let mut tree = vec![0; values.len() + 1];
+        tree[1..].copy_from_slice(values);
+
+        for i in 1..tree.len() {
+            let up = i + (i & i.wrapping_neg());
+            if up < tree.len() {
+                tree[up] += tree[i];
+            }
+        }
+
+        Fenwick { tree }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // 0 indexed point update
+    pub fn add(&mut self, index: usize, delta: i64) {
+        let mut i = index + 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    // sum of the values in [0, end)
+    pub fn prefix_sum(&self, end: usize) -> i64 {
+        let mut sum = 0;
+        let mut i = end;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+
+        sum
+    }
+
+    // sum of the values in [l, r)
+    pub fn range_sum(&self, l: usize, r: usize) -> i64 {
+        self.prefix_sum(r) - self.prefix_sum(l)
+    }
+}
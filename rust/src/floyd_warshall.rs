@@ -0,0 +1,105 @@
+// dense all pairs shortest paths for small graphs
+// reference - https://cp-algorithms.com/graph/all-pair-shortest-path-floyd-warshall.html
+#[derive(Debug, Clone)]
+pub struct AllPairsShortestPaths {
+    n: usize,
+    // dist[a][b] is the minimum cost from a to b, None when unreachable
+    dist: Vec<Vec<Option<u64>>>,
+    // next[a][b] is the node after a on a minimum cost route to b
+    // only meaningful when dist[a][b] is Some
+    next: Vec<Vec<usize>>,
+}
+
+impl AllPairsShortestPaths {
+    pub fn new(n: usize) -> Self {
+        let mut dist = vec![vec![None; n]; n];
+        let mut next = vec![vec![usize::MAX; n]; n];
+
+        for i in 0..n {
+            dist[i][i] = Some(0);
+            next[i][i] = i;
+        }
+
+        AllPairsShortestPaths { n, dist, next }
+    }
+
+    // records a direct edge, parallel edges keep the cheapest
+    // distances are only correct again after calling compute
+    pub fn add_edge(&mut self, start: usize, end: usize, cost: u64) {
+        if self.dist[start][end].is_none_or(|dist| cost < dist) {
+            self.dist[start][end] = Some(cost);
+            self.next[start][end] = end;
+        }
+    }
+
+    // floyd warshall over all recorded edges, O(n^3)
+    pub fn compute(&mut self) {
+        for mid in 0..self.n {
+            for start in 0..self.n {
+                let to_mid = match self.dist[start][mid] {
+                    Some(dist) => dist,
+                    None => continue,
+                };
+
+                for end in 0..self.n {
+                    if let Some(from_mid) = self.dist[mid][end] {
+                        let through_mid = to_mid + from_mid;
+
+                        if self.dist[start][end].is_none_or(|dist| through_mid < dist) {
+                            self.dist[start][end] = Some(through_mid);
+                            self.next[start][end] = self.next[start][mid];
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // adds an edge to already computed distances in O(n^2)
+    // only routes that use the new edge can improve, and such a
+    // route is a shortest route to start followed by the edge
+    // and a shortest route from end
+    pub fn add_edge_and_update(&mut self, start: usize, end: usize, cost: u64) {
+        for from in 0..self.n {
+            let to_start = match self.dist[from][start] {
+                Some(dist) => dist,
+                None => continue,
+            };
+
+            let first_hop = if from == start {
+                end
+            } else {
+                self.next[from][start]
+            };
+
+            for to in 0..self.n {
+                if let Some(from_end) = self.dist[end][to] {
+                    let through_edge = to_start + cost + from_end;
+
+                    if self.dist[from][to].is_none_or(|dist| through_edge < dist) {
+                        self.dist[from][to] = Some(through_edge);
+                        self.next[from][to] = first_hop;
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn dist(&self, start: usize, end: usize) -> Option<u64> {
+        self.dist[start][end]
+    }
+
+    // nodes of a minimum cost route, both ends inclusive
+    pub fn path(&self, start: usize, end: usize) -> Option<Vec<usize>> {
+        self.dist[start][end]?;
+
+        let mut path = vec![start];
+        let mut cur = start;
+        while cur != end {
+            cur = self.next[cur][end];
+            path.push(cur);
+        }
+
+        Some(path)
+    }
+}
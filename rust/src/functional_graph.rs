@@ -0,0 +1,169 @@
+use crate::{lca::LcaTable, tree::RootedTree};
+
+// graph where every node has exactly one outgoing edge, each component
+// is a single cycle with trees of tail nodes hanging into it
+// the tails are kept as a rooted tree below a virtual root joined to
+// every cycle node, so walking a tail is a k-th ancestor query
+#[derive(Debug, Clone)]
+pub struct FunctionalGraph {
+    next: Vec<usize>,
+    // id of the cycle every node eventually reaches
+    cycle_id: Vec<usize>,
+    // position on the cycle for cycle nodes, usize::MAX for tail nodes
+    cycle_pos: Vec<usize>,
+    // steps from a node until it reaches its cycle, 0 on the cycle
+    tail_len: Vec<usize>,
+    // first cycle node reached from a node
+    entry: Vec<usize>,
+    // nodes of each cycle in walking order
+    cycles: Vec<Vec<usize>>,
+    tails: RootedTree,
+    tail_jumps: LcaTable,
+}
+
+impl FunctionalGraph {
+    pub fn new(next: Vec<usize>) -> Self {
+        let n = next.len();
+        let mut cycle_id = vec![usize::MAX; n];
+        let mut cycle_pos = vec![usize::MAX; n];
+        let mut tail_len = vec![0; n];
+        let mut entry = vec![usize::MAX; n];
+        let mut cycles: Vec<Vec<usize>> = Vec::new();
+
+        // index of a node on the walk that first reached it
+        let mut walk_index = vec![usize::MAX; n];
+        let mut walk = Vec::new();
+
+        for start in 0..n {
+            if cycle_id[start] != usize::MAX {
+                continue;
+            }
+
+            // follow edges until reaching a node that is already
+            // resolved or that was seen earlier on this walk
+            let mut node = start;
+            while cycle_id[node] == usize::MAX && walk_index[node] == usize::MAX {
+                walk_index[node] = walk.len();
+                walk.push(node);
+                node = next[node];
+            }
+
+            // the walk closed a new cycle
+            if cycle_id[node] == usize::MAX {
+                let cycle = walk.split_off(walk_index[node]);
+                for (pos, &member) in cycle.iter().enumerate() {
+                    cycle_id[member] = cycles.len();
+                    cycle_pos[member] = pos;
+                    entry[member] = member;
+                }
+                cycles.push(cycle);
+            }
+
+            // what is left of the walk is a tail into a resolved node
+            for &tail_node in walk.iter().rev() {
+                let to = next[tail_node];
+                cycle_id[tail_node] = cycle_id[to];
+                entry[tail_node] = entry[to];
+                tail_len[tail_node] = tail_len[to] + 1;
+            }
+            walk.clear();
+        }
+
+        let mut parents: Vec<usize> = (0..n)
+            .map(|node| {
+                if cycle_pos[node] == usize::MAX {
+                    next[node]
+                } else {
+                    n
+                }
+            })
+            .collect();
+        parents.push(usize::MAX);
+
+        let tails = RootedTree::from_parents(&parents);
+        let tail_jumps = LcaTable::new(&tails);
+
+        FunctionalGraph {
+            next,
+            cycle_id,
+            cycle_pos,
+            tail_len,
+            entry,
+            cycles,
+            tails,
+            tail_jumps,
+        }
+    }
+
+    pub fn next(&self, node: usize) -> usize {
+        self.next[node]
+    }
+
+    pub fn cycles(&self) -> &[Vec<usize>] {
+        &self.cycles
+    }
+
+    pub fn cycle_id(&self, node: usize) -> usize {
+        self.cycle_id[node]
+    }
+
+    // length of the cycle node eventually reaches
+    pub fn cycle_len(&self, node: usize) -> usize {
+        self.cycles[self.cycle_id[node]].len()
+    }
+
+    // position of a cycle node in cycles()[cycle_id], None for tail nodes
+    pub fn cycle_pos(&self, node: usize) -> Option<usize> {
+        Some(self.cycle_pos[node]).filter(|&pos| pos != usize::MAX)
+    }
+
+    pub fn is_on_cycle(&self, node: usize) -> bool {
+        self.cycle_pos[node] != usize::MAX
+    }
+
+    pub fn tail_len(&self, node: usize) -> usize {
+        self.tail_len[node]
+    }
+
+    // first cycle node reached from node
+    pub fn entry(&self, node: usize) -> usize {
+        self.entry[node]
+    }
+
+    // number of steps from node until some node is visited a second time
+    pub fn steps_until_repeat(&self, node: usize) -> usize {
+        self.tail_len[node] + self.cycle_len(node)
+    }
+
+    // node reached after k steps, O(log n) for any k
+    pub fn jump(&self, node: usize, k: usize) -> usize {
+        if k < self.tail_len[node] {
+            return self.tail_jumps.kth_ancestor(node, k).unwrap();
+        }
+
+        let cycle = &self.cycles[self.cycle_id[node]];
+        let entry = self.entry[node];
+        let steps_on_cycle = (k - self.tail_len[node]) % cycle.len();
+        cycle[(self.cycle_pos[entry] + steps_on_cycle) % cycle.len()]
+    }
+
+    // fewest steps from a to b, None when b is never reached
+    pub fn distance(&self, a: usize, b: usize) -> Option<usize> {
+        if self.cycle_id[a] != self.cycle_id[b] {
+            return None;
+        }
+
+        if self.is_on_cycle(b) {
+            // walk the tail then go around the cycle up to b
+            let cycle_len = self.cycles[self.cycle_id[b]].len();
+            let entry_pos = self.cycle_pos[self.entry[a]];
+            let around = (self.cycle_pos[b] + cycle_len - entry_pos) % cycle_len;
+            Some(self.tail_len[a] + around)
+        } else if self.tails.is_ancestor(b, a) {
+            // a tail node is only reached from the tail nodes behind it
+            Some(self.tail_len[a] - self.tail_len[b])
+        } else {
+            None
+        }
+    }
+}
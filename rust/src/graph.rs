@@ -0,0 +1,37 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Edge {
+    pub cost: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Edge {
+    pub fn new(start: usize, end: usize, cost: usize) -> Self {
+        Edge { cost, start, end }
+    }
+}
+
+// weighted adjacency list, edges[i] holds the outgoing edges of node i
+#[derive(Debug, Clone)]
+pub struct Graph {
+    pub n: usize,
+    pub edges: Vec<Vec<Edge>>,
+}
+
+impl Graph {
+    pub fn new(n: usize) -> Self {
+        Graph {
+            n,
+            edges: vec![Vec::new(); n],
+        }
+    }
+
+    pub fn add_edge(&mut self, start: usize, end: usize, cost: usize) {
+        self.edges[start].push(Edge::new(start, end, cost));
+    }
+
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize, cost: usize) {
+        self.add_edge(a, b, cost);
+        self.add_edge(b, a, cost);
+    }
+}
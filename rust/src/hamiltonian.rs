@@ -0,0 +1,174 @@
+use crate::graph::Graph;
+use std::collections::HashMap;
+
+// bitmask dp over paths visiting every node exactly once, meant for
+// graphs with at most 20 nodes where a state is the set of visited
+// nodes together with the node the path currently ends at
+// edges are directed, parallel edges count as different paths
+
+// number of paths from start to end visiting every node exactly once
+// counts are stored as u32 so the table of 2^n * n states stays small,
+// which needs the modulo to fit in 32 bits
+pub fn count_paths(graph: &Graph, start: usize, end: usize, modulo: usize) -> usize {
+    assert!(modulo as u64 <= 1 << 32);
+
+    let n = graph.n;
+    let full = (1 << n) - 1;
+    let mut paths = vec![0u32; (1 << n) * n];
+    paths[(1 << start) * n + start] = (1 % modulo) as u32;
+
+    for mask in 0..=full {
+        // every path begins at start and reaches end as its last node
+        if mask & (1 << start) == 0 || (mask & (1 << end) != 0 && mask != full) {
+            continue;
+        }
+
+        for node in 0..n {
+            let count = paths[mask * n + node] as usize;
+            if count == 0 {
+                continue;
+            }
+
+            for edge in graph.edges[node].iter() {
+                if mask & (1 << edge.end) != 0 {
+                    continue;
+                }
+
+                let index = (mask | 1 << edge.end) * n + edge.end;
+                let mut sum = paths[index] as usize + count;
+                if sum >= modulo {
+                    sum -= modulo;
+                }
+                paths[index] = sum as u32;
+            }
+        }
+    }
+
+    paths[full * n + end] as usize
+}
+
+// same count as count_paths keeping only the states reachable from
+// start, one layer of paths with the same number of nodes at a time
+// slower per state but uses memory proportional to the widest layer
+pub fn count_paths_sparse(graph: &Graph, start: usize, end: usize, modulo: usize) -> usize {
+    let n = graph.n;
+    let full = (1 << n) - 1;
+    let mut layer: HashMap<(usize, usize), usize> = HashMap::new();
+    layer.insert((1 << start, start), 1 % modulo);
+
+    for _ in 1..n {
+        let mut next_layer = HashMap::new();
+
+        for (&(mask, node), &count) in layer.iter() {
+            // end has to be the last node of the path
+            if node == end {
+                continue;
+            }
+
+            for edge in graph.edges[node].iter() {
+                if mask & (1 << edge.end) != 0 {
+                    continue;
+                }
+
+                let paths = next_layer
+                    .entry((mask | 1 << edge.end, edge.end))
+                    .or_insert(0);
+                *paths = (*paths + count) % modulo;
+            }
+        }
+
+        layer = next_layer;
+    }
+
+    layer.get(&(full, end)).copied().unwrap_or(0)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tour {
+    pub cost: usize,
+    // every node once starting from node 0, the last node
+    // has an edge back to the first
+    pub nodes: Vec<usize>,
+}
+
+// cheapest cycle visiting every node exactly once, held karp in
+// O(2^n n^2) with the tour rebuilt from the cost table instead of
+// storing a parent for every state
+pub fn shortest_tour(graph: &Graph) -> Option<Tour> {
+    let n = graph.n;
+    match n {
+        0 => return None,
+        1 => {
+            return Some(Tour {
+                cost: 0,
+                nodes: vec![0],
+            })
+        }
+        _ => {}
+    }
+
+    // cheapest of the parallel edges between two nodes
+    let mut cost = vec![vec![usize::MAX; n]; n];
+    for (node, node_cost) in cost.iter_mut().enumerate() {
+        for edge in graph.edges[node].iter() {
+            if edge.end != node {
+                node_cost[edge.end] = node_cost[edge.end].min(edge.cost);
+            }
+        }
+    }
+
+    // best[mask * n + node] is the cheapest path from node 0 through
+    // the nodes of mask ending at node
+    let full = (1 << n) - 1;
+    let mut best = vec![usize::MAX; (1 << n) * n];
+    best[n] = 0;
+
+    for mask in (1..=full).step_by(2) {
+        for node in 0..n {
+            let so_far = best[mask * n + node];
+            if so_far == usize::MAX {
+                continue;
+            }
+
+            for (next, &edge_cost) in cost[node].iter().enumerate() {
+                if mask & (1 << next) != 0 || edge_cost == usize::MAX {
+                    continue;
+                }
+
+                let index = (mask | 1 << next) * n + next;
+                best[index] = best[index].min(so_far + edge_cost);
+            }
+        }
+    }
+
+    let (total_cost, mut node) = (1..n)
+        .filter(|&node| best[full * n + node] != usize::MAX && cost[node][0] != usize::MAX)
+        .map(|node| (best[full * n + node] + cost[node][0], node))
+        .min()?;
+
+    // walk back through states whose cost plus the connecting
+    // edge matches the cost of the current state
+    let mut nodes = vec![node];
+    let mut mask = full;
+    while node != 0 {
+        let prev_mask = mask ^ (1 << node);
+        let prev = (0..n)
+            .find(|&prev| {
+                let prev_cost = best[prev_mask * n + prev];
+                prev_cost != usize::MAX
+                    && cost[prev][node] != usize::MAX
+                    && prev_cost + cost[prev][node] == best[mask * n + node]
+            })
+            .unwrap();
+
+        nodes.push(prev);
+        mask = prev_mask;
+        node = prev;
+    }
+
+    nodes.reverse();
+    Some(Tour {
+        cost: total_cost,
+        nodes,
+    })
+}
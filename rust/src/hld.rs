@@ -0,0 +1,150 @@
+use crate::{
+    segment_tree::{Monoid, SegmentTree},
+    tree::RootedTree,
+};
+use std::ops::Range;
+
+// heavy light decomposition, every node continues the chain of the
+// child with the largest subtree so any root path crosses O(log n)
+// chains, positions follow a dfs that visits the heavy child first so
+// each chain and each subtree is a contiguous range of positions
+#[derive(Debug, Clone)]
+pub struct HeavyLight {
+    parent: Vec<usize>,
+    depth: Vec<usize>,
+    subtree_size: Vec<usize>,
+    // top node of the chain containing a node
+    head: Vec<usize>,
+    pub pos: Vec<usize>,
+}
+
+impl HeavyLight {
+    pub fn new(tree: &RootedTree) -> Self {
+        let n = tree.n();
+        let mut head = vec![tree.root; n];
+        let mut pos = vec![0; n];
+        let mut next_pos = 0;
+
+        let mut pending = vec![tree.root];
+        while let Some(node) = pending.pop() {
+            pos[node] = next_pos;
+            next_pos += 1;
+
+            let children = &tree.children[node];
+            let heavy = children
+                .iter()
+                .copied()
+                .max_by_key(|&child| tree.subtree_size[child]);
+
+            // light children start their own chain, the heavy child is
+            // pushed last so it is visited right after node
+            for &child in children.iter() {
+                if Some(child) != heavy {
+                    head[child] = child;
+                    pending.push(child);
+                }
+            }
+
+            if let Some(heavy) = heavy {
+                head[heavy] = head[node];
+                pending.push(heavy);
+            }
+        }
+
+        HeavyLight {
+            parent: tree.parent.clone(),
+            depth: tree.depth.clone(),
+            subtree_size: tree.subtree_size.clone(),
+            head,
+            pos,
+        }
+    }
+
+    // position ranges covering the nodes of the path between a and b
+    // in no particular order
+    pub fn path_ranges(&self, mut a: usize, mut b: usize) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+
+        // lift whichever end sits on the chain with the deeper head
+        while self.head[a] != self.head[b] {
+            if self.depth[self.head[a]] < self.depth[self.head[b]] {
+                std::mem::swap(&mut a, &mut b);
+            }
+
+            ranges.push(self.pos[self.head[a]]..self.pos[a] + 1);
+            a = self.parent[self.head[a]];
+        }
+
+        if self.depth[a] > self.depth[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        ranges.push(self.pos[a]..self.pos[b] + 1);
+
+        ranges
+    }
+
+    pub fn subtree_range(&self, node: usize) -> Range<usize> {
+        self.pos[node]..self.pos[node] + self.subtree_size[node]
+    }
+
+    pub fn lca(&self, mut a: usize, mut b: usize) -> usize {
+        while self.head[a] != self.head[b] {
+            if self.depth[self.head[a]] < self.depth[self.head[b]] {
+                std::mem::swap(&mut a, &mut b);
+            }
+            a = self.parent[self.head[a]];
+        }
+
+        if self.depth[a] < self.depth[b] {
+            a
+        } else {
+            b
+        }
+    }
+}
+
+// node values kept in a segment tree at their hld positions, path
+// folds combine the chain pieces in no fixed order so M should be
+// commutative, subtree folds are a single range
+#[derive(Debug, Clone)]
+pub struct PathQueries<M: Monoid> {
+    pub decomposition: HeavyLight,
+    values: SegmentTree<M>,
+}
+
+impl<M: Monoid> PathQueries<M> {
+    pub fn new(tree: &RootedTree, values: &[M::Value]) -> Self {
+        let decomposition = HeavyLight::new(tree);
+
+        let mut by_pos = vec![M::identity(); values.len()];
+        for (node, value) in values.iter().enumerate() {
+            by_pos[decomposition.pos[node]] = value.clone();
+        }
+
+        PathQueries {
+            decomposition,
+            values: SegmentTree::new(by_pos),
+        }
+    }
+
+    pub fn update_value(&mut self, node: usize, value: M::Value) {
+        self.values
+            .update_value(self.decomposition.pos[node], value);
+    }
+
+    // fold over every node of the path between a and b, both included
+    pub fn path(&self, a: usize, b: usize) -> M::Value {
+        self.decomposition
+            .path_ranges(a, b)
+            .into_iter()
+            .fold(M::identity(), |value, range| {
+                M::combine(&value, &self.values.find_for_range(range.start, range.end))
+            })
+    }
+
+    // fold over every node in the subtree of node
+    pub fn subtree(&self, node: usize) -> M::Value {
+        let range = self.decomposition.subtree_range(node);
+        self.values.find_for_range(range.start, range.end)
+    }
+}
@@ -0,0 +1,86 @@
+use crate::tree::RootedTree;
+use std::collections::HashMap;
+
+// canonical shapes of trees with the ahu encoding, a subtree is identified
+// by the sorted list of its children shapes and every distinct list gets
+// the next small id, so two subtrees have equal ids exactly when they are
+// isomorphic as long as the ids come from the same interner
+#[derive(Debug, Clone, Default)]
+pub struct ShapeInterner {
+    ids: HashMap<Vec<usize>, usize>,
+}
+
+impl ShapeInterner {
+    pub fn new() -> Self {
+        ShapeInterner::default()
+    }
+
+    // number of distinct shapes seen so far
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    // shape id of the subtree below every node
+    pub fn subtree_shapes(&mut self, tree: &RootedTree) -> Vec<usize> {
+        let mut shape = vec![0; tree.n()];
+
+        for &node in tree.postorder.iter() {
+            let mut children: Vec<usize> = tree.children[node]
+                .iter()
+                .map(|&child| shape[child])
+                .collect();
+            children.sort_unstable();
+
+            let next_id = self.ids.len();
+            shape[node] = *self.ids.entry(children).or_insert(next_id);
+        }
+
+        shape
+    }
+
+    pub fn rooted_shape(&mut self, tree: &RootedTree) -> usize {
+        self.subtree_shapes(tree)[tree.root]
+    }
+
+    // an unrooted tree is rooted at each of its one or two centroids,
+    // which any isomorphism maps onto each other, and the smaller
+    // shape id is kept
+    pub fn unrooted_shape(&mut self, n: usize, edges: &[(usize, usize)]) -> usize {
+        let tree = RootedTree::from_edges(n, edges, 0);
+
+        tree.centroids()
+            .into_iter()
+            .map(|centroid| self.rooted_shape(&RootedTree::from_edges(n, edges, centroid)))
+            .min()
+            .unwrap()
+    }
+}
+
+pub fn rooted_isomorphic(a: &RootedTree, b: &RootedTree) -> bool {
+    let mut interner = ShapeInterner::new();
+    a.n() == b.n() && interner.rooted_shape(a) == interner.rooted_shape(b)
+}
+
+// both trees have n nodes given as edge lists
+pub fn unrooted_isomorphic(n: usize, a: &[(usize, usize)], b: &[(usize, usize)]) -> bool {
+    let mut interner = ShapeInterner::new();
+    interner.unrooted_shape(n, a) == interner.unrooted_shape(n, b)
+}
+
+// nodes grouped by the shape of their subtree, a shape gets its id
+// after the shapes of its children so groups of leaves come first
+pub fn group_subtrees_by_shape(tree: &RootedTree) -> Vec<Vec<usize>> {
+    let mut interner = ShapeInterner::new();
+    let shape = interner.subtree_shapes(tree);
+
+    let mut groups = vec![Vec::new(); interner.len()];
+    for (node, &id) in shape.iter().enumerate() {
+        groups[id].push(node);
+    }
+
+    groups
+}
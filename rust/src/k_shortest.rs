@@ -0,0 +1,88 @@
+use crate::graph::Graph;
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Walk {
+    pub cost: usize,
+    // nodes from source to target, both inclusive
+    pub nodes: Vec<usize>,
+}
+
+// a popped search entry, parent is the index of the settled
+// entry it was reached from or usize::MAX for the source
+#[derive(Debug, Clone, Copy)]
+struct Settled {
+    node: usize,
+    cost: usize,
+    parent: usize,
+}
+
+// dijkstra where every node may be settled up to k times
+// the i-th time a node is popped is the cost of its i-th cheapest walk
+// since any walk to the target only extends one of the k cheapest
+// walks to each intermediate node, O((n + m) k log(m k)) overall
+fn settle_k_times(graph: &Graph, source: usize, target: usize, k: usize) -> Vec<Settled> {
+    let mut settled = Vec::new();
+    let mut times_settled = vec![0; graph.n];
+    let mut next_visit_min_heap = BinaryHeap::new();
+
+    next_visit_min_heap.push(Reverse((0, source, usize::MAX)));
+
+    while let Some(Reverse((cost, node, parent))) = next_visit_min_heap.pop() {
+        if times_settled[node] == k {
+            continue;
+        }
+
+        times_settled[node] += 1;
+        settled.push(Settled { node, cost, parent });
+
+        if node == target && times_settled[node] == k {
+            break;
+        }
+
+        let entry = settled.len() - 1;
+        for edge in graph.edges[node].iter() {
+            if times_settled[edge.end] < k {
+                next_visit_min_heap.push(Reverse((cost + edge.cost, edge.end, entry)));
+            }
+        }
+    }
+
+    settled
+}
+
+// costs of the k cheapest walks from source to target in
+// non decreasing order, fewer are returned if fewer walks exist
+pub fn k_shortest_costs(graph: &Graph, source: usize, target: usize, k: usize) -> Vec<usize> {
+    settle_k_times(graph, source, target, k)
+        .into_iter()
+        .filter(|entry| entry.node == target)
+        .map(|entry| entry.cost)
+        .collect()
+}
+
+// the k cheapest walks from source to target along with their nodes
+// walks may repeat nodes and edges
+pub fn k_shortest_walks(graph: &Graph, source: usize, target: usize, k: usize) -> Vec<Walk> {
+    let settled = settle_k_times(graph, source, target, k);
+
+    settled
+        .iter()
+        .filter(|entry| entry.node == target)
+        .map(|entry| {
+            let mut nodes = vec![entry.node];
+            let mut parent = entry.parent;
+
+            while parent != usize::MAX {
+                nodes.push(settled[parent].node);
+                parent = settled[parent].parent;
+            }
+
+            nodes.reverse();
+            Walk {
+                cost: entry.cost,
+                nodes,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,202 @@
+use crate::tree::RootedTree;
+
+// lowest common ancestor queries over a rooted tree
+// LcaTable answers in O(log n) after O(n log n) binary lifting and also
+// walks up or along paths, EulerTourLca answers lca in O(1) from a
+// sparse table over the euler tour but cannot jump to ancestors
+// the lca_benchmark bin times both on the same trees and queries, with
+// 2 * 10^5 nodes and queries in release builds the euler tour answered
+// about ten times faster on random and path shaped trees while taking
+// one and a half to two and a half times as long to build
+
+// binary lifting table, the 2^j-th ancestor of node is stored at
+// up[node * levels + j] so the jumps of one node share cache lines
+// and ancestors above the root stay at the root
+#[derive(Debug, Clone)]
+pub struct LcaTable {
+    levels: usize,
+    up: Vec<usize>,
+    depth: Vec<usize>,
+}
+
+impl LcaTable {
+    pub fn new(tree: &RootedTree) -> Self {
+        let n = tree.n();
+        let levels = (usize::BITS - n.leading_zeros()).max(1) as usize;
+        let mut up = vec![tree.root; n * levels];
+
+        // parents come before their children in the preorder
+        for &node in tree.preorder.iter().skip(1) {
+            let base = node * levels;
+            up[base] = tree.parent[node];
+
+            for j in 1..levels {
+                let mid = up[base + j - 1];
+                up[base + j] = up[mid * levels + j - 1];
+            }
+        }
+
+        LcaTable {
+            levels,
+            up,
+            depth: tree.depth.clone(),
+        }
+    }
+
+    fn jump(&self, node: usize, j: usize) -> usize {
+        self.up[node * self.levels + j]
+    }
+
+    pub fn depth(&self, node: usize) -> usize {
+        self.depth[node]
+    }
+
+    // ancestor k levels above node, None when k is more than its depth
+    pub fn kth_ancestor(&self, mut node: usize, k: usize) -> Option<usize> {
+        if k > self.depth[node] {
+            return None;
+        }
+
+        for j in 0..self.levels {
+            if k >> j & 1 == 1 {
+                node = self.jump(node, j);
+            }
+        }
+
+        Some(node)
+    }
+
+    pub fn lca(&self, a: usize, b: usize) -> usize {
+        let (deep, shallow) = if self.depth[a] >= self.depth[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+
+        // lift the deeper node to the same depth first
+        let deep = self
+            .kth_ancestor(deep, self.depth[deep] - self.depth[shallow])
+            .unwrap();
+        if deep == shallow {
+            return deep;
+        }
+
+        // jump both as long as they stay below the common ancestor
+        let (mut a, mut b) = (deep, shallow);
+        for j in (0..self.levels).rev() {
+            if self.jump(a, j) != self.jump(b, j) {
+                a = self.jump(a, j);
+                b = self.jump(b, j);
+            }
+        }
+
+        self.jump(a, 0)
+    }
+
+    // number of edges on the path between a and b
+    pub fn dist(&self, a: usize, b: usize) -> usize {
+        self.depth[a] + self.depth[b] - 2 * self.depth[self.lca(a, b)]
+    }
+
+    // node k steps along the path from a to b, a itself for k = 0
+    // and None when the path has fewer than k edges
+    pub fn kth_on_path(&self, a: usize, b: usize, k: usize) -> Option<usize> {
+        let lca = self.lca(a, b);
+        let up_steps = self.depth[a] - self.depth[lca];
+        let down_steps = self.depth[b] - self.depth[lca];
+
+        if k <= up_steps {
+            self.kth_ancestor(a, k)
+        } else if k <= up_steps + down_steps {
+            // the remaining steps are walked back up from b
+            self.kth_ancestor(b, up_steps + down_steps - k)
+        } else {
+            None
+        }
+    }
+}
+
+// sparse table over the euler tour which lists a node on entry and again
+// after each of its children, the lca of a and b is the shallowest node
+// between their first appearances
+#[derive(Debug, Clone)]
+pub struct EulerTourLca {
+    first: Vec<usize>,
+    depth: Vec<usize>,
+    // table[j][i] is the shallowest node of tour[i..i + 2^j]
+    table: Vec<Vec<usize>>,
+}
+
+impl EulerTourLca {
+    pub fn new(tree: &RootedTree) -> Self {
+        let n = tree.n();
+        let mut tour = Vec::with_capacity(2 * n - 1);
+        let mut first = vec![0; n];
+
+        // frames of (node, index of the next child to visit)
+        let mut stack = vec![(tree.root, 0)];
+        first[tree.root] = 0;
+        tour.push(tree.root);
+
+        while let Some((node, child_index)) = stack.last_mut() {
+            let node = *node;
+            match tree.children[node].get(*child_index) {
+                Some(&child) => {
+                    *child_index += 1;
+                    first[child] = tour.len();
+                    tour.push(child);
+                    stack.push((child, 0));
+                }
+                None => {
+                    stack.pop();
+                    if let Some(&(parent, _)) = stack.last() {
+                        tour.push(parent);
+                    }
+                }
+            }
+        }
+
+        let depth = tree.depth.clone();
+        let shallower = |a: usize, b: usize| if depth[a] <= depth[b] { a } else { b };
+
+        let mut table = vec![tour];
+        let mut width = 1;
+        while 2 * width <= table[0].len() {
+            let prev = table.last().unwrap();
+            let level = (0..prev.len() - width)
+                .map(|i| shallower(prev[i], prev[i + width]))
+                .collect();
+            table.push(level);
+            width *= 2;
+        }
+
+        EulerTourLca {
+            first,
+            depth,
+            table,
+        }
+    }
+
+    pub fn lca(&self, a: usize, b: usize) -> usize {
+        let (left, right) = if self.first[a] <= self.first[b] {
+            (self.first[a], self.first[b])
+        } else {
+            (self.first[b], self.first[a])
+        };
+
+        // two overlapping power of two windows cover left..=right
+        let level = (usize::BITS - 1 - (right - left + 1).leading_zeros()) as usize;
+        let x = self.table[level][left];
+        let y = self.table[level][right + 1 - (1 << level)];
+
+        if self.depth[x] <= self.depth[y] {
+            x
+        } else {
+            y
+        }
+    }
+
+    pub fn dist(&self, a: usize, b: usize) -> usize {
+        self.depth[a] + self.depth[b] - 2 * self.depth[self.lca(a, b)]
+    }
+}
@@ -0,0 +1,30 @@
+// shared building blocks for the solutions in src/bin
+// each module is self contained and works on 0 indexed nodes
+pub mod bellman_ford;
+pub mod biconnected;
+pub mod centroid;
+pub mod coloring;
+pub mod cycle;
+pub mod diameter;
+pub mod dijkstra;
+pub mod dynamic_connectivity;
+pub mod euler;
+pub mod fenwick;
+pub mod floyd_warshall;
+pub mod functional_graph;
+pub mod graph;
+pub mod hamiltonian;
+pub mod hld;
+pub mod isomorphism;
+pub mod k_shortest;
+pub mod lca;
+pub mod rerooting;
+pub mod segment_tree;
+pub mod shortest_path_dag;
+pub mod small_to_large;
+pub mod spanning_tree;
+pub mod topological;
+pub mod tree;
+pub mod tree_queries;
+pub mod two_sat;
+pub mod union_find;
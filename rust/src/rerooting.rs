@@ -0,0 +1,80 @@
+use crate::tree::RootedTree;
+
+// dp over a tree evaluated for every choice of root in O(n)
+// the value of a part of the tree hanging below some node is the merge
+// of its lifted neighbour parts, lift(value, node) turns the value of the
+// part rooted at node into its contribution across the edge above node
+// merge has to be associative and, since every root sees its neighbours
+// in a different order, commutative with identity as its neutral value
+pub fn reroot<T, M, L>(tree: &RootedTree, identity: T, merge: M, lift: L) -> Vec<T>
+where
+    T: Clone,
+    M: Fn(&T, &T) -> T,
+    L: Fn(&T, usize) -> T,
+{
+    let n = tree.n();
+
+    // value of the subtree below every node for the given root
+    let mut down = vec![identity.clone(); n];
+    for &node in tree.postorder.iter() {
+        down[node] = tree.children[node]
+            .iter()
+            .fold(identity.clone(), |value, &child| {
+                merge(&value, &lift(&down[child], child))
+            });
+    }
+
+    // up[node] is the value of the part rooted at the parent of node
+    // once the subtree of node is cut off
+    let mut up = vec![identity.clone(); n];
+    let mut answer = vec![identity.clone(); n];
+
+    for &node in tree.preorder.iter() {
+        let from_parent = if node == tree.root {
+            identity.clone()
+        } else {
+            lift(&up[node], tree.parent[node])
+        };
+        answer[node] = merge(&down[node], &from_parent);
+
+        // prefix and suffix merges leave out one child at a time
+        let children = &tree.children[node];
+        let lifted: Vec<T> = children
+            .iter()
+            .map(|&child| lift(&down[child], child))
+            .collect();
+
+        let mut suffix = vec![identity.clone(); children.len() + 1];
+        for i in (0..children.len()).rev() {
+            suffix[i] = merge(&lifted[i], &suffix[i + 1]);
+        }
+
+        let mut prefix = from_parent;
+        for (i, &child) in children.iter().enumerate() {
+            up[child] = merge(&prefix, &suffix[i + 1]);
+            prefix = merge(&prefix, &lifted[i]);
+        }
+    }
+
+    answer
+}
+
+// largest number of edges from every node to any other node
+pub fn eccentricities(tree: &RootedTree) -> Vec<usize> {
+    reroot(tree, 0, |a, b| *a.max(b), |height, _| height + 1)
+}
+
+// sum of the number of edges from every node to all other nodes
+pub fn distance_sums(tree: &RootedTree) -> Vec<usize> {
+    // values are (sum of distances, number of nodes) of a part
+    // seen from its root, the root itself is not counted
+    reroot(
+        tree,
+        (0, 0),
+        |a, b| (a.0 + b.0, a.1 + b.1),
+        |&(sum, count), _| (sum + count + 1, count + 1),
+    )
+    .into_iter()
+    .map(|(sum, _)| sum)
+    .collect()
+}
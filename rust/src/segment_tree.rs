@@ -0,0 +1,128 @@
+use std::fmt::Debug;
+
+// values combined by an associative operation with a neutral element
+pub trait Monoid {
+    type Value: Clone + Debug;
+
+    fn identity() -> Self::Value;
+    fn combine(a: &Self::Value, b: &Self::Value) -> Self::Value;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Min;
+
+impl Monoid for Min {
+    type Value = usize;
+
+    fn identity() -> usize {
+        usize::MAX
+    }
+
+    fn combine(a: &usize, b: &usize) -> usize {
+        *a.min(b)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Max;
+
+impl Monoid for Max {
+    type Value = usize;
+
+    fn identity() -> usize {
+        0
+    }
+
+    fn combine(a: &usize, b: &usize) -> usize {
+        *a.max(b)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Sum;
+
+impl Monoid for Sum {
+    type Value = usize;
+
+    fn identity() -> usize {
+        0
+    }
+
+    fn combine(a: &usize, b: &usize) -> usize {
+        a + b
+    }
+}
+
+// bottom up segment tree with point updates and range folds, the
+// leaves sit at array_len.. and node i covers nodes 2i and 2i + 1
+// folds keep the left to right order so M need not be commutative
+#[derive(Debug, Clone)]
+pub struct SegmentTree<M: Monoid> {
+    array_len: usize,
+    tree: Vec<M::Value>,
+}
+
+impl<M: Monoid> SegmentTree<M> {
+    pub fn new(values: Vec<M::Value>) -> Self {
+        let array_len = values.len();
+        let mut tree = vec![M::identity(); array_len];
+        tree.extend(values);
+
+        for i in (1..array_len).rev() {
+            tree[i] = M::combine(&tree[2 * i], &tree[2 * i + 1]);
+        }
+
+        SegmentTree { array_len, tree }
+    }
+
+    pub fn len(&self) -> usize {
+        self.array_len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.array_len == 0
+    }
+
+    pub fn get(&self, index: usize) -> &M::Value {
+        &self.tree[self.array_len + index]
+    }
+
+    // 0 indexed update on original array element
+    pub fn update_value(&mut self, index: usize, value: M::Value) {
+        let mut tree_index = self.array_len + index;
+        self.tree[tree_index] = value;
+
+        while tree_index > 1 {
+            tree_index /= 2;
+            self.tree[tree_index] =
+                M::combine(&self.tree[2 * tree_index], &self.tree[2 * tree_index + 1]);
+        }
+    }
+
+    // fold over the range [l, r) i.e. left inclusive, right exclusive
+    pub fn find_for_range(&self, query_left: usize, query_right: usize) -> M::Value {
+        let mut left_value = M::identity();
+        let mut right_value = M::identity();
+        let mut l = query_left + self.array_len;
+        let mut r = query_right + self.array_len;
+
+        // an odd l is a right child so it is taken as is and the range
+        // moves on to the next pair, likewise for an odd r from the right
+        while l < r {
+            if l & 1 == 1 {
+                left_value = M::combine(&left_value, &self.tree[l]);
+                l += 1;
+            }
+
+            if r & 1 == 1 {
+                r -= 1;
+                right_value = M::combine(&self.tree[r], &right_value);
+            }
+
+            l /= 2;
+            r /= 2;
+        }
+
+        M::combine(&left_value, &right_value)
+    }
+}
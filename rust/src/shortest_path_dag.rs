@@ -0,0 +1,186 @@
+use crate::{
+    dijkstra::shortest_paths,
+    graph::{Edge, Graph},
+};
+
+// the subgraph of all edges that lie on some minimum cost route
+// from the source, an edge a -> b is kept when cost[a] + edge cost == cost[b]
+// statistics over shortest routes are folds over this dag in
+// topological order, so new statistics never touch the search
+// zero cost edges are allowed as long as they form no cycle
+#[derive(Debug, Clone)]
+pub struct ShortestPathDag {
+    pub source: usize,
+    // minimum cost from source, usize::MAX when unreachable
+    pub cost: Vec<usize>,
+    // kept edges grouped by their end node
+    in_edges: Vec<Vec<Edge>>,
+    // kept edges grouped by their start node
+    out_edges: Vec<Vec<Edge>>,
+    // reachable nodes in topological order of the dag
+    order: Vec<usize>,
+}
+
+impl ShortestPathDag {
+    pub fn new(graph: &Graph, source: usize) -> Self {
+        let cost = shortest_paths(graph, source).costs().to_vec();
+        let mut in_edges = vec![Vec::new(); graph.n];
+        let mut out_edges = vec![Vec::new(); graph.n];
+
+        for edges in graph.edges.iter() {
+            for &edge in edges {
+                if cost[edge.start] != usize::MAX && cost[edge.start] + edge.cost == cost[edge.end]
+                {
+                    in_edges[edge.end].push(edge);
+                    out_edges[edge.start].push(edge);
+                }
+            }
+        }
+
+        // sorting by cost is only topological for positive costs so
+        // kahn's algorithm is used, it never releases the nodes of a zero
+        // cost cycle and those are rejected as the kept edges are no dag
+        let mut in_degree: Vec<usize> = in_edges.iter().map(|edges| edges.len()).collect();
+        let mut order = vec![source];
+        let mut index = 0;
+
+        while index < order.len() {
+            let node = order[index];
+            index += 1;
+
+            for edge in out_edges[node].iter() {
+                in_degree[edge.end] -= 1;
+                if in_degree[edge.end] == 0 {
+                    order.push(edge.end);
+                }
+            }
+        }
+
+        let reachable = cost.iter().filter(|&&cost| cost != usize::MAX).count();
+        assert_eq!(
+            order.len(),
+            reachable,
+            "zero cost cycle reachable from the source"
+        );
+
+        ShortestPathDag {
+            source,
+            cost,
+            in_edges,
+            out_edges,
+            order,
+        }
+    }
+
+    pub fn in_edges(&self, node: usize) -> &[Edge] {
+        &self.in_edges[node]
+    }
+
+    pub fn out_edges(&self, node: usize) -> &[Edge] {
+        &self.out_edges[node]
+    }
+
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    // computes a value for every node from the values of its dag
+    // predecessors, extend carries a predecessor value over an edge
+    // and merge combines the values arriving from different edges
+    // unreachable nodes get None
+    pub fn fold<T, E, M>(&self, source_value: T, extend: E, merge: M) -> Vec<Option<T>>
+    where
+        T: Clone,
+        E: Fn(&T, &Edge) -> T,
+        M: Fn(T, T) -> T,
+    {
+        let mut values: Vec<Option<T>> = vec![None; self.cost.len()];
+        values[self.source] = Some(source_value);
+
+        for &node in self.order.iter().skip(1) {
+            values[node] = self.in_edges[node]
+                .iter()
+                .filter_map(|edge| values[edge.start].as_ref().map(|value| extend(value, edge)))
+                .reduce(&merge);
+        }
+
+        values
+    }
+
+    // number of minimum cost routes to every node modulo the given value
+    pub fn count_paths(&self, modulo: usize) -> Vec<usize> {
+        self.fold(1 % modulo, |&routes, _| routes, |a, b| (a + b) % modulo)
+            .into_iter()
+            .map(|routes| routes.unwrap_or(0))
+            .collect()
+    }
+
+    // fewest flights among minimum cost routes
+    pub fn min_hops(&self) -> Vec<Option<usize>> {
+        self.fold(0, |&hops, _| hops + 1, usize::min)
+    }
+
+    // most flights among minimum cost routes
+    pub fn max_hops(&self) -> Vec<Option<usize>> {
+        self.fold(0, |&hops, _| hops + 1, usize::max)
+    }
+
+    // nodes from which target can be reached along dag edges
+    fn reaches(&self, target: usize) -> Vec<bool> {
+        let mut reaches = vec![false; self.cost.len()];
+        if self.cost[target] == usize::MAX {
+            return reaches;
+        }
+
+        let mut pending = vec![target];
+        reaches[target] = true;
+
+        while let Some(node) = pending.pop() {
+            for edge in self.in_edges[node].iter() {
+                if !reaches[edge.start] {
+                    reaches[edge.start] = true;
+                    pending.push(edge.start);
+                }
+            }
+        }
+
+        reaches
+    }
+
+    // lexicographically smallest node sequence among minimum cost
+    // routes to target, picking the smallest next node that can
+    // still reach target is optimal because every route shares
+    // the prefix chosen so far
+    pub fn smallest_path(&self, target: usize) -> Option<Vec<usize>> {
+        let reaches = self.reaches(target);
+        if !reaches[self.source] {
+            return None;
+        }
+
+        let mut path = vec![self.source];
+        let mut cur = self.source;
+
+        while cur != target {
+            cur = self.out_edges[cur]
+                .iter()
+                .map(|edge| edge.end)
+                .filter(|&next| reaches[next])
+                .min()
+                .unwrap();
+            path.push(cur);
+        }
+
+        Some(path)
+    }
+
+    // every edge that lies on at least one minimum cost route to target
+    pub fn edges_to(&self, target: usize) -> Vec<Edge> {
+        let reaches = self.reaches(target);
+
+        self.order
+            .iter()
+            .filter(|&&node| reaches[node])
+            .flat_map(|&node| self.in_edges[node].iter().copied())
+            .collect()
+    }
+}
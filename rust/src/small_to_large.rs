@@ -0,0 +1,78 @@
+use crate::tree::RootedTree;
+use std::{collections::HashSet, hash::Hash};
+
+// statistics over a set of nodes that grows and shrinks one node at a time
+pub trait SubtreeState {
+    type Answer;
+
+    fn add(&mut self, node: usize);
+    fn remove(&mut self, node: usize);
+    // called when the set holds exactly the subtree of node
+    fn answer(&self, node: usize) -> Self::Answer;
+}
+
+// dsu on tree, also known as sack, answers a query for every subtree
+// with O(n log n) add and remove calls
+// the state of the child with the largest subtree is kept for its parent
+// while the other children are added again, a node is only added again
+// when it is in a light subtree and it has O(log n) light ancestors
+pub fn subtree_answers<S: SubtreeState>(tree: &RootedTree, state: &mut S) -> Vec<S::Answer> {
+    let n = tree.n();
+    let heavy: Vec<Option<usize>> = (0..n)
+        .map(|node| {
+            tree.children[node]
+                .iter()
+                .copied()
+                .max_by_key(|&child| tree.subtree_size[child])
+        })
+        .collect();
+
+    // postorder that visits the light children before the heavy one,
+    // built as the reverse of a preorder taking the heavy child first
+    let mut order = Vec::with_capacity(n);
+    let mut pending = vec![tree.root];
+    while let Some(node) = pending.pop() {
+        order.push(node);
+
+        for &child in tree.children[node].iter() {
+            if Some(child) != heavy[node] {
+                pending.push(child);
+            }
+        }
+        pending.extend(heavy[node]);
+    }
+    order.reverse();
+
+    // every subtree starts from an empty state, light subtrees are cleared
+    // once answered so the heavy subtree is all that is left for a node
+    let mut answers: Vec<Option<S::Answer>> = (0..n).map(|_| None).collect();
+    for &node in order.iter() {
+        state.add(node);
+        for &child in tree.children[node].iter() {
+            if Some(child) != heavy[node] {
+                for &member in tree.subtree(child) {
+                    state.add(member);
+                }
+            }
+        }
+
+        answers[node] = Some(state.answer(node));
+
+        let parent = tree.parent[node];
+        if parent == usize::MAX || heavy[parent] != Some(node) {
+            for &member in tree.subtree(node) {
+                state.remove(member);
+            }
+        }
+    }
+
+    answers.into_iter().map(Option::unwrap).collect()
+}
+
+// union of two sets moving the elements of the smaller one, so an element
+// that keeps being merged moves O(log n) times as its set at least doubles
+pub fn merge_into_larger<T: Eq + Hash>(a: HashSet<T>, b: HashSet<T>) -> HashSet<T> {
+    let (mut larger, smaller) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    larger.extend(smaller);
+    larger
+}
@@ -0,0 +1,250 @@
+use crate::{
+    biconnected::LowLink,
+    graph::{Edge, Graph},
+    union_find::UnionFind,
+};
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanningForest {
+    pub total_cost: usize,
+    pub edges: Vec<Edge>,
+    // number of trees, a spanning tree exists only when this is 1
+    pub components: usize,
+}
+
+impl SpanningForest {
+    pub fn is_spanning_tree(&self) -> bool {
+        self.components <= 1
+    }
+}
+
+// kruskal over an undirected edge list, returns a minimum spanning
+// forest with one tree per connected component
+pub fn kruskal(n: usize, edges: &[Edge]) -> SpanningForest {
+    let (chosen, components) = kruskal_indices(n, edges);
+
+    SpanningForest {
+        total_cost: chosen.iter().map(|&i| edges[i].cost).sum(),
+        edges: chosen.iter().map(|&i| edges[i]).collect(),
+        components,
+    }
+}
+
+// indices of the chosen edges and the number of trees
+fn kruskal_indices(n: usize, edges: &[Edge]) -> (Vec<usize>, usize) {
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    order.sort_by_key(|&i| edges[i].cost);
+
+    let mut uf = UnionFind::new(n);
+    let chosen = order
+        .into_iter()
+        .filter(|&i| uf.create_union(edges[i].start, edges[i].end))
+        .collect();
+
+    (chosen, uf.components)
+}
+
+// lazy prim over an undirected graph whose edges were added in
+// both directions, stale heap entries into visited nodes are skipped
+pub fn prim(graph: &Graph) -> SpanningForest {
+    let mut visited = vec![false; graph.n];
+    let mut forest = Vec::new();
+    let mut total_cost = 0;
+    let mut components = 0;
+    let mut next_edge_min_heap = BinaryHeap::new();
+
+    for root in 0..graph.n {
+        if visited[root] {
+            continue;
+        }
+
+        components += 1;
+        visited[root] = true;
+        next_edge_min_heap.extend(graph.edges[root].iter().map(|&edge| Reverse(edge)));
+
+        while let Some(Reverse(edge)) = next_edge_min_heap.pop() {
+            if visited[edge.end] {
+                continue;
+            }
+
+            visited[edge.end] = true;
+            total_cost += edge.cost;
+            forest.push(edge);
+
+            for &next_edge in graph.edges[edge.end].iter() {
+                if !visited[next_edge.end] {
+                    next_edge_min_heap.push(Reverse(next_edge));
+                }
+            }
+        }
+    }
+
+    SpanningForest {
+        total_cost,
+        edges: forest,
+        components,
+    }
+}
+
+// membership of an edge in the minimum spanning trees of a graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MstMembership {
+    Never,
+    // part of at least one but not all
+    Sometimes,
+    Always,
+}
+
+// edges of equal cost are processed as a group, an edge is in some
+// mst when it joins different components of the forest built from
+// strictly cheaper edges, and in every mst when it is also a bridge
+// of the group graph over those components
+pub fn mst_membership(n: usize, edges: &[Edge]) -> Vec<MstMembership> {
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    order.sort_by_key(|&i| edges[i].cost);
+
+    let mut uf = UnionFind::new(n);
+    let mut membership = vec![MstMembership::Never; edges.len()];
+    let mut group_start = 0;
+
+    while group_start < order.len() {
+        let cost = edges[order[group_start]].cost;
+        let mut group_end = group_start;
+        while group_end < order.len() && edges[order[group_end]].cost == cost {
+            group_end += 1;
+        }
+
+        let group = &order[group_start..group_end];
+
+        let mut candidates = Vec::new();
+        for &i in group {
+            let root_a = uf.find_root(edges[i].start);
+            let root_b = uf.find_root(edges[i].end);
+
+            if root_a != root_b {
+                membership[i] = MstMembership::Sometimes;
+                candidates.push((i, root_a, root_b));
+            }
+        }
+
+        for i in group_bridges(&candidates) {
+            membership[i] = MstMembership::Always;
+        }
+
+        for &(i, _, _) in candidates.iter() {
+            uf.create_union(edges[i].start, edges[i].end);
+        }
+
+        group_start = group_end;
+    }
+
+    membership
+}
+
+// bridges of a small multigraph given as (edge id, a, b), returns edge ids
+fn group_bridges(candidates: &[(usize, usize, usize)]) -> Vec<usize> {
+    // compress the component roots to 0..k
+    let mut nodes: Vec<usize> = candidates
+        .iter()
+        .flat_map(|&(_, a, b)| vec![a, b])
+        .collect();
+    nodes.sort_unstable();
+    nodes.dedup();
+
+    let local = |node: usize| nodes.binary_search(&node).unwrap();
+    let group_edges: Vec<(usize, usize)> = candidates
+        .iter()
+        .map(|&(_, a, b)| (local(a), local(b)))
+        .collect();
+
+    LowLink::new(nodes.len(), &group_edges)
+        .bridges()
+        .into_iter()
+        .map(|slot| candidates[slot].0)
+        .collect()
+}
+
+// cost of the cheapest spanning tree that differs from a minimum
+// spanning tree in at least one edge, None when no such tree exists
+// found by swapping one non tree edge for the most expensive tree
+// edge on the path between its ends, O(m log n) with lifting tables
+pub fn second_best_mst(n: usize, edges: &[Edge]) -> Option<usize> {
+    let (chosen, components) = kruskal_indices(n, edges);
+    if components != 1 {
+        return None;
+    }
+
+    let mut tree = Graph::new(n);
+    let mut in_tree = vec![false; edges.len()];
+    for &i in chosen.iter() {
+        tree.add_undirected_edge(edges[i].start, edges[i].end, edges[i].cost);
+        in_tree[i] = true;
+    }
+    let total_cost: usize = chosen.iter().map(|&i| edges[i].cost).sum();
+
+    // up[j][v] is the 2^j-th ancestor of v and up_max[j][v] the most
+    // expensive edge on the way there, the tree is rooted at 0
+    let levels = (usize::BITS - n.leading_zeros()) as usize;
+    let mut up = vec![vec![0; n]; levels];
+    let mut up_max = vec![vec![0; n]; levels];
+    let mut depth = vec![0; n];
+    let mut visited = vec![false; n];
+    let mut pending = vec![0];
+    visited[0] = true;
+
+    while let Some(node) = pending.pop() {
+        for edge in tree.edges[node].iter() {
+            if !visited[edge.end] {
+                visited[edge.end] = true;
+                up[0][edge.end] = node;
+                up_max[0][edge.end] = edge.cost;
+                depth[edge.end] = depth[node] + 1;
+                pending.push(edge.end);
+            }
+        }
+    }
+
+    for j in 1..levels {
+        for v in 0..n {
+            let mid = up[j - 1][v];
+            up[j][v] = up[j - 1][mid];
+            up_max[j][v] = up_max[j - 1][v].max(up_max[j - 1][mid]);
+        }
+    }
+
+    let max_on_path = |mut a: usize, mut b: usize| {
+        let mut max_cost = 0;
+        if depth[a] < depth[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+
+        for j in (0..levels).rev() {
+            if depth[a] - depth[b] >= 1 << j {
+                max_cost = max_cost.max(up_max[j][a]);
+                a = up[j][a];
+            }
+        }
+
+        if a == b {
+            return max_cost;
+        }
+
+        for j in (0..levels).rev() {
+            if up[j][a] != up[j][b] {
+                max_cost = max_cost.max(up_max[j][a]).max(up_max[j][b]);
+                a = up[j][a];
+                b = up[j][b];
+            }
+        }
+
+        max_cost.max(up_max[0][a]).max(up_max[0][b])
+    };
+
+    edges
+        .iter()
+        .zip(in_tree)
+        .filter(|&(edge, in_tree)| !in_tree && edge.start != edge.end)
+        .map(|(edge, _)| total_cost + edge.cost - max_on_path(edge.start, edge.end))
+        .min()
+}
@@ -0,0 +1,168 @@
+use std::cmp::{max, min};
+
+// directed acyclic graph over an adjacency list with a topological order
+// dp helpers walk the order so every predecessor of a node is
+// final before the node itself is processed
+#[derive(Debug, Clone)]
+pub struct Dag {
+    adjacent: Vec<Vec<usize>>,
+    order: Vec<usize>,
+}
+
+impl Dag {
+    // kahn's algorithm, fails with a directed cycle in visit order
+    // where the last node has an edge back to the first
+    pub fn new(adjacent: Vec<Vec<usize>>) -> Result<Self, Vec<usize>> {
+        let n = adjacent.len();
+        let mut in_degree = vec![0; n];
+        for ends in adjacent.iter() {
+            for &end in ends {
+                in_degree[end] += 1;
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).filter(|&node| in_degree[node] == 0).collect();
+        let mut index = 0;
+
+        while index < order.len() {
+            let node = order[index];
+            index += 1;
+
+            for &next in adjacent[node].iter() {
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    order.push(next);
+                }
+            }
+        }
+
+        if order.len() < n {
+            return Err(Dag::find_cycle(&adjacent, &in_degree));
+        }
+
+        Ok(Dag { adjacent, order })
+    }
+
+    // nodes left with a positive in degree after kahn's algorithm each
+    // have a predecessor that was also left, so walking predecessors
+    // must eventually repeat a node
+    fn find_cycle(adjacent: &[Vec<usize>], in_degree: &[usize]) -> Vec<usize> {
+        let n = adjacent.len();
+        let mut predecessor = vec![usize::MAX; n];
+        for (start, ends) in adjacent.iter().enumerate() {
+            if in_degree[start] == 0 {
+                continue;
+            }
+
+            for &end in ends {
+                if in_degree[end] > 0 {
+                    predecessor[end] = start;
+                }
+            }
+        }
+
+        let mut seen = vec![false; n];
+        let mut cur = (0..n).find(|&node| in_degree[node] > 0).unwrap();
+        while !seen[cur] {
+            seen[cur] = true;
+            cur = predecessor[cur];
+        }
+
+        let mut cycle = vec![cur];
+        let mut node = predecessor[cur];
+        while node != cur {
+            cycle.push(node);
+            node = predecessor[node];
+        }
+
+        // predecessors were followed backwards
+        cycle.reverse();
+        cycle
+    }
+
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    pub fn adjacent(&self) -> &[Vec<usize>] {
+        &self.adjacent
+    }
+
+    // path from source to target with the most edges
+    pub fn longest_path(&self, source: usize, target: usize) -> Option<Vec<usize>> {
+        let n = self.adjacent.len();
+        let mut length = vec![usize::MAX; n];
+        let mut parent = vec![usize::MAX; n];
+        length[source] = 0;
+
+        for &node in self.order.iter() {
+            if length[node] == usize::MAX {
+                continue;
+            }
+
+            for &next in self.adjacent[node].iter() {
+                if length[next] == usize::MAX || length[node] + 1 > length[next] {
+                    length[next] = length[node] + 1;
+                    parent[next] = node;
+                }
+            }
+        }
+
+        if length[target] == usize::MAX {
+            return None;
+        }
+
+        let mut path = vec![target];
+        let mut cur = target;
+        while cur != source {
+            cur = parent[cur];
+            path.push(cur);
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    // number of paths from source to every node modulo the given value
+    pub fn count_paths(&self, source: usize, modulo: usize) -> Vec<usize> {
+        let mut paths = vec![0; self.adjacent.len()];
+        paths[source] = 1 % modulo;
+
+        for &node in self.order.iter() {
+            if paths[node] == 0 {
+                continue;
+            }
+
+            for &next in self.adjacent[node].iter() {
+                paths[next] = (paths[next] + paths[node]) % modulo;
+            }
+        }
+
+        paths
+    }
+
+    // fewest and most edges over all paths from source to each node
+    pub fn path_length_bounds(&self, source: usize) -> Vec<Option<(usize, usize)>> {
+        let mut bounds: Vec<Option<(usize, usize)>> = vec![None; self.adjacent.len()];
+        bounds[source] = Some((0, 0));
+
+        for &node in self.order.iter() {
+            let (shortest, longest) = match bounds[node] {
+                Some(bound) => bound,
+                None => continue,
+            };
+
+            for &next in self.adjacent[node].iter() {
+                bounds[next] = Some(match bounds[next] {
+                    Some((next_shortest, next_longest)) => (
+                        min(next_shortest, shortest + 1),
+                        max(next_longest, longest + 1),
+                    ),
+                    None => (shortest + 1, longest + 1),
+                });
+            }
+        }
+
+        bounds
+    }
+}
@@ -0,0 +1,160 @@
+// tree rooted at a fixed node with the usual traversal data computed
+// by an explicit stack so path shaped trees do not overflow the stack
+// every subtree is a contiguous range tin..tout of the preorder
+#[derive(Debug, Clone)]
+pub struct RootedTree {
+    pub root: usize,
+    // usize::MAX for the root
+    pub parent: Vec<usize>,
+    pub children: Vec<Vec<usize>>,
+    pub depth: Vec<usize>,
+    pub subtree_size: Vec<usize>,
+    pub preorder: Vec<usize>,
+    pub postorder: Vec<usize>,
+    // position of a node in the preorder
+    pub tin: Vec<usize>,
+    // one past the position of the last node of its subtree
+    pub tout: Vec<usize>,
+}
+
+impl RootedTree {
+    // parents[node] is the parent of node and usize::MAX marks the root
+    pub fn from_parents(parents: &[usize]) -> Self {
+        let n = parents.len();
+        let mut children = vec![Vec::new(); n];
+        let mut root = usize::MAX;
+
+        for (node, &parent) in parents.iter().enumerate() {
+            if parent == usize::MAX {
+                assert_eq!(root, usize::MAX, "parent list has several roots");
+                root = node;
+            } else {
+                children[parent].push(node);
+            }
+        }
+
+        RootedTree::from_children(root, children)
+    }
+
+    // undirected tree with n nodes given as node pairs
+    pub fn from_edges(n: usize, edges: &[(usize, usize)], root: usize) -> Self {
+        let mut adjacent = vec![Vec::new(); n];
+        for &(a, b) in edges {
+            adjacent[a].push(b);
+            adjacent[b].push(a);
+        }
+
+        // orient every edge away from the root
+        let mut children = vec![Vec::new(); n];
+        let mut visited = vec![false; n];
+        let mut pending = vec![root];
+        visited[root] = true;
+
+        while let Some(node) = pending.pop() {
+            for &next in adjacent[node].iter() {
+                if !visited[next] {
+                    visited[next] = true;
+                    children[node].push(next);
+                    pending.push(next);
+                }
+            }
+        }
+
+        RootedTree::from_children(root, children)
+    }
+
+    fn from_children(root: usize, children: Vec<Vec<usize>>) -> Self {
+        let n = children.len();
+        let mut parent = vec![usize::MAX; n];
+        let mut depth = vec![0; n];
+        let mut preorder = Vec::with_capacity(n);
+
+        // children are pushed in reverse so they are visited in list order
+        let mut pending = vec![root];
+        while let Some(node) = pending.pop() {
+            preorder.push(node);
+
+            for &child in children[node].iter().rev() {
+                parent[child] = node;
+                depth[child] = depth[node] + 1;
+                pending.push(child);
+            }
+        }
+
+        assert_eq!(
+            preorder.len(),
+            n,
+            "not every node is reachable from the root"
+        );
+
+        let mut tin = vec![0; n];
+        for (position, &node) in preorder.iter().enumerate() {
+            tin[node] = position;
+        }
+
+        // children come after their parent in the preorder
+        let mut subtree_size = vec![1; n];
+        for &node in preorder.iter().skip(1).rev() {
+            subtree_size[parent[node]] += subtree_size[node];
+        }
+
+        let tout: Vec<usize> = (0..n).map(|node| tin[node] + subtree_size[node]).collect();
+
+        // nodes finished before a node are the earlier preorder nodes
+        // that are not its ancestors followed by its own descendants
+        let mut postorder = vec![0; n];
+        for node in 0..n {
+            postorder[tin[node] - depth[node] + subtree_size[node] - 1] = node;
+        }
+
+        RootedTree {
+            root,
+            parent,
+            children,
+            depth,
+            subtree_size,
+            preorder,
+            postorder,
+            tin,
+            tout,
+        }
+    }
+
+    // number of nodes
+    pub fn n(&self) -> usize {
+        self.parent.len()
+    }
+
+    // a node is an ancestor of itself
+    pub fn is_ancestor(&self, a: usize, b: usize) -> bool {
+        self.tin[a] <= self.tin[b] && self.tout[b] <= self.tout[a]
+    }
+
+    // nodes in the subtree of node in preorder
+    pub fn subtree(&self, node: usize) -> &[usize] {
+        &self.preorder[self.tin[node]..self.tout[node]]
+    }
+
+    pub fn is_leaf(&self, node: usize) -> bool {
+        self.children[node].is_empty()
+    }
+
+    // nodes whose removal leaves no part with more than half of the
+    // nodes, a tree has one or two of them and two are adjacent
+    pub fn centroids(&self) -> Vec<usize> {
+        let n = self.n();
+
+        (0..n)
+            .filter(|&node| {
+                let largest_part = self.children[node]
+                    .iter()
+                    .map(|&child| self.subtree_size[child])
+                    .max()
+                    .unwrap_or(0)
+                    .max(n - self.subtree_size[node]);
+
+                2 * largest_part <= n
+            })
+            .collect()
+    }
+}
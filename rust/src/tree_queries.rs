@@ -0,0 +1,80 @@
+use crate::{fenwick::Fenwick, tree::RootedTree};
+
+// node values on a tree flattened by its preorder, every subtree is the
+// range tin..tout of positions so subtree and root path sums turn into
+// fenwick range sums and range updates
+
+// subtree sums under point assignments, values are kept at the preorder
+// position of their node and a subtree sum is the sum of its range
+#[derive(Debug, Clone)]
+pub struct SubtreeSums {
+    tin: Vec<usize>,
+    tout: Vec<usize>,
+    values: Vec<i64>,
+    sums: Fenwick,
+}
+
+impl SubtreeSums {
+    pub fn new(tree: &RootedTree, values: &[i64]) -> Self {
+        let by_pos: Vec<i64> = tree.preorder.iter().map(|&node| values[node]).collect();
+
+        SubtreeSums {
+            tin: tree.tin.clone(),
+            tout: tree.tout.clone(),
+            values: values.to_vec(),
+            sums: Fenwick::from_values(&by_pos),
+        }
+    }
+
+    pub fn set(&mut self, node: usize, value: i64) {
+        self.sums.add(self.tin[node], value - self.values[node]);
+        self.values[node] = value;
+    }
+
+    pub fn subtree_sum(&self, node: usize) -> i64 {
+        self.sums.range_sum(self.tin[node], self.tout[node])
+    }
+}
+
+// root path sums under point assignments, a node value counts for every
+// node of its subtree so it is added over the subtree range of a
+// difference array and a path sum is the prefix sum at the node
+#[derive(Debug, Clone)]
+pub struct PathSums {
+    tin: Vec<usize>,
+    tout: Vec<usize>,
+    values: Vec<i64>,
+    differences: Fenwick,
+}
+
+impl PathSums {
+    pub fn new(tree: &RootedTree, values: &[i64]) -> Self {
+        let mut path_sums = PathSums {
+            tin: tree.tin.clone(),
+            tout: tree.tout.clone(),
+            values: vec![0; tree.n()],
+            differences: Fenwick::new(tree.n()),
+        };
+
+        for (node, &value) in values.iter().enumerate() {
+            path_sums.set(node, value);
+        }
+
+        path_sums
+    }
+
+    pub fn set(&mut self, node: usize, value: i64) {
+        let delta = value - self.values[node];
+        self.values[node] = value;
+
+        self.differences.add(self.tin[node], delta);
+        if self.tout[node] < self.differences.len() {
+            self.differences.add(self.tout[node], -delta);
+        }
+    }
+
+    // sum of the values from the root down to node, both included
+    pub fn root_path_sum(&self, node: usize) -> i64 {
+        self.differences.prefix_sum(self.tin[node] + 1)
+    }
+}
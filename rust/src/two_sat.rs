@@ -0,0 +1,300 @@
+use std::{collections::VecDeque, ops::Not};
+
+// a literal is a variable or its negation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Lit {
+    var: usize,
+    negated: bool,
+}
+
+impl Lit {
+    pub fn pos(var: usize) -> Self {
+        Lit {
+            var,
+            negated: false,
+        }
+    }
+
+    pub fn neg(var: usize) -> Self {
+        Lit { var, negated: true }
+    }
+
+    pub fn new(var: usize, value: bool) -> Self {
+        Lit {
+            var,
+            negated: !value,
+        }
+    }
+
+    pub fn var(self) -> usize {
+        self.var
+    }
+
+    pub fn is_negated(self) -> bool {
+        self.negated
+    }
+
+    // position of the literal in the implication graph
+    // x is stored at 2 * x and not x at 2 * x + 1
+    fn node(self) -> usize {
+        2 * self.var + self.negated as usize
+    }
+
+    fn from_node(node: usize) -> Self {
+        Lit {
+            var: node / 2,
+            negated: node % 2 == 1,
+        }
+    }
+}
+
+impl Not for Lit {
+    type Output = Lit;
+
+    fn not(self) -> Lit {
+        Lit {
+            var: self.var,
+            negated: !self.negated,
+        }
+    }
+}
+
+// proof that a formula is unsatisfiable
+// var and not var are in the same strongly connected component
+// path starts at the positive literal of var, passes through
+// the negative literal and returns to the positive literal
+// each consecutive pair in the path is an implication edge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contradiction {
+    pub var: usize,
+    pub path: Vec<Lit>,
+}
+
+// 2 sat solver over an implication graph
+// reference - https://cp-algorithms.com/graph/2SAT.html
+#[derive(Debug, Clone)]
+pub struct TwoSat {
+    n_vars: usize,
+    implications: Vec<Vec<usize>>,
+}
+
+impl TwoSat {
+    pub fn new(n_vars: usize) -> Self {
+        TwoSat {
+            n_vars,
+            implications: vec![Vec::new(); 2 * n_vars],
+        }
+    }
+
+    pub fn n_vars(&self) -> usize {
+        self.n_vars
+    }
+
+    // adds a fresh variable and returns its index
+    pub fn add_var(&mut self) -> usize {
+        self.implications.push(Vec::new());
+        self.implications.push(Vec::new());
+        self.n_vars += 1;
+        self.n_vars - 1
+    }
+
+    // add clause a v b
+    pub fn add_clause(&mut self, a: Lit, b: Lit) {
+        // a v b is equivalent to not a -> b and not b -> a
+        self.implications[(!a).node()].push(b.node());
+        self.implications[(!b).node()].push(a.node());
+    }
+
+    // add implication a -> b i.e. clause not a v b
+    pub fn add_implication(&mut self, a: Lit, b: Lit) {
+        self.add_clause(!a, b);
+    }
+
+    // forces literal a to be true
+    pub fn set(&mut self, a: Lit) {
+        self.add_clause(a, a);
+    }
+
+    // at most one literal of the group is true
+    // uses prefix auxiliary variables so only O(k) clauses are added
+    // where prefix i is true when any of the first i + 1 literals is true
+    pub fn at_most_one(&mut self, group: &[Lit]) {
+        if group.len() <= 1 {
+            return;
+        }
+
+        let mut prev_prefix = Lit::pos(self.add_var());
+        self.add_implication(group[0], prev_prefix);
+
+        for &lit in &group[1..] {
+            let prefix = Lit::pos(self.add_var());
+
+            self.add_implication(lit, prefix);
+            self.add_implication(prev_prefix, prefix);
+            // an earlier literal being true forbids this one
+            self.add_implication(prev_prefix, !lit);
+
+            prev_prefix = prefix;
+        }
+    }
+
+    // exactly one literal of the group is true
+    // at least one of k literals is a k-clause, so this can
+    // only be expressed in 2 sat for groups of one or two literals
+    pub fn exactly_one(&mut self, group: &[Lit]) {
+        assert!(
+            !group.is_empty() && group.len() <= 2,
+            "exactly one is only expressible in 2 sat for 1 or 2 literals"
+        );
+
+        match group {
+            [a] => self.set(*a),
+            [a, b] => {
+                self.add_clause(*a, *b);
+                self.add_clause(!*a, !*b);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    // returns a satisfying assignment indexed by variable
+    // auxiliary variables from at_most_one are included
+    pub fn solve(&self) -> Option<Vec<bool>> {
+        self.try_solve().ok()
+    }
+
+    // returns a satisfying assignment or the contradiction
+    // that makes the formula unsatisfiable
+    pub fn try_solve(&self) -> Result<Vec<bool>, Contradiction> {
+        let component = self.strongly_connected_components();
+
+        for var in 0..self.n_vars {
+            let (x, not_x) = (Lit::pos(var).node(), Lit::neg(var).node());
+
+            if component[x] == component[not_x] {
+                return Err(self.contradiction(var));
+            }
+        }
+
+        // components are numbered in topological order of the
+        // condensation so x must be true when not x -> x holds
+        // i.e. x appears later in the order than not x
+        Ok((0..self.n_vars)
+            .map(|var| component[Lit::pos(var).node()] > component[Lit::neg(var).node()])
+            .collect())
+    }
+
+    // iterative kosaraju, returns component id for each literal node
+    fn strongly_connected_components(&self) -> Vec<usize> {
+        let n_nodes = self.implications.len();
+
+        let mut transpose = vec![Vec::new(); n_nodes];
+        for (start, ends) in self.implications.iter().enumerate() {
+            for &end in ends {
+                transpose[end].push(start);
+            }
+        }
+
+        // first pass records nodes by finishing time
+        let mut visited = vec![false; n_nodes];
+        let mut order = Vec::with_capacity(n_nodes);
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+
+        for root in 0..n_nodes {
+            if visited[root] {
+                continue;
+            }
+
+            visited[root] = true;
+            stack.push((root, 0));
+
+            while let Some((node, edge_index)) = stack.pop() {
+                if let Some(&next) = self.implications[node].get(edge_index) {
+                    stack.push((node, edge_index + 1));
+
+                    if !visited[next] {
+                        visited[next] = true;
+                        stack.push((next, 0));
+                    }
+                } else {
+                    order.push(node);
+                }
+            }
+        }
+
+        // second pass over the transpose in reverse finishing order
+        let mut component = vec![usize::MAX; n_nodes];
+        let mut cur_component = 0;
+        let mut pending = Vec::new();
+
+        for &root in order.iter().rev() {
+            if component[root] != usize::MAX {
+                continue;
+            }
+
+            component[root] = cur_component;
+            pending.push(root);
+
+            while let Some(node) = pending.pop() {
+                for &next in &transpose[node] {
+                    if component[next] == usize::MAX {
+                        component[next] = cur_component;
+                        pending.push(next);
+                    }
+                }
+            }
+
+            cur_component += 1;
+        }
+
+        component
+    }
+
+    // var and not var share a component so both are reachable
+    // from each other, join the two shortest paths
+    fn contradiction(&self, var: usize) -> Contradiction {
+        let x = Lit::pos(var).node();
+        let not_x = Lit::neg(var).node();
+
+        let mut path = self.shortest_path(x, not_x);
+        path.pop();
+        path.extend(self.shortest_path(not_x, x));
+
+        Contradiction {
+            var,
+            path: path.into_iter().map(Lit::from_node).collect(),
+        }
+    }
+
+    // bfs path between two distinct literal nodes, both ends inclusive
+    fn shortest_path(&self, start: usize, end: usize) -> Vec<usize> {
+        let mut parent = vec![usize::MAX; self.implications.len()];
+        let mut queue = VecDeque::new();
+
+        parent[start] = start;
+        queue.push_back(start);
+
+        while let Some(node) = queue.pop_front() {
+            if node == end {
+                break;
+            }
+
+            for &next in &self.implications[node] {
+                if parent[next] == usize::MAX {
+                    parent[next] = node;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut path = vec![end];
+        let mut cur = end;
+        while cur != start {
+            cur = parent[cur];
+            path.push(cur);
+        }
+
+        path.reverse();
+        path
+    }
+}
@@ -0,0 +1,174 @@
+use std::{cmp::max, mem::swap};
+
+// a recorded union so it can be undone
+#[derive(Debug, Clone, Copy)]
+struct Union {
+    // root attached below another root, usize::MAX when the
+    // union did not merge anything
+    child: usize,
+    // largest component size before the union
+    largest: usize,
+    // max value of the surviving root before the union
+    root_max: i64,
+}
+
+// disjoint set union with union by size and per component aggregates
+// by default finds use path halving, with rollback enabled path
+// compression is turned off since it would rewrite parents outside
+// of the recorded history, finds are then O(log n) through union by
+// size alone
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    pub components: usize,
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    // aggregates of the custom node values, only valid at roots
+    value_sum: Vec<i64>,
+    value_max: Vec<i64>,
+    largest: usize,
+    // None unless rollback is enabled
+    history: Option<Vec<Union>>,
+}
+
+impl UnionFind {
+    pub fn new(n: usize) -> Self {
+        UnionFind {
+            components: n,
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            value_sum: vec![0; n],
+            value_max: vec![0; n],
+            largest: if n > 0 { 1 } else { 0 },
+            history: None,
+        }
+    }
+
+    // record unions so they can be undone with rollback
+    pub fn with_rollback(mut self) -> Self {
+        self.history = Some(Vec::new());
+        self
+    }
+
+    // custom value for every node, aggregated per component
+    // should be set before any union
+    pub fn with_values(mut self, values: &[i64]) -> Self {
+        self.value_sum = values.to_vec();
+        self.value_max = values.to_vec();
+        self
+    }
+
+    pub fn find_root(&mut self, cur: usize) -> usize {
+        let mut cur = cur;
+
+        if self.history.is_some() {
+            while self.parent[cur] != cur {
+                cur = self.parent[cur];
+            }
+
+            return cur;
+        }
+
+        loop {
+            if self.parent[cur] == cur {
+                break cur;
+            }
+
+            // path halving, point every other node to its grandparent
+            self.parent[cur] = self.parent[self.parent[cur]];
+            cur = self.parent[cur];
+        }
+    }
+
+    // returns false when a and b were already connected
+    pub fn create_union(&mut self, a: usize, b: usize) -> bool {
+        let mut root_a = self.find_root(a);
+        let mut root_b = self.find_root(b);
+
+        if root_a == root_b {
+            if let Some(history) = self.history.as_mut() {
+                history.push(Union {
+                    child: usize::MAX,
+                    largest: self.largest,
+                    root_max: 0,
+                });
+            }
+
+            return false;
+        }
+
+        // attach the smaller component below the larger one
+        if self.size[root_a] > self.size[root_b] {
+            swap(&mut root_a, &mut root_b);
+        }
+
+        if let Some(history) = self.history.as_mut() {
+            history.push(Union {
+                child: root_a,
+                largest: self.largest,
+                root_max: self.value_max[root_b],
+            });
+        }
+
+        self.parent[root_a] = root_b;
+        self.size[root_b] += self.size[root_a];
+        self.value_sum[root_b] += self.value_sum[root_a];
+        self.value_max[root_b] = max(self.value_max[root_b], self.value_max[root_a]);
+        self.largest = max(self.largest, self.size[root_b]);
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find_root(a) == self.find_root(b)
+    }
+
+    pub fn component_size(&mut self, i: usize) -> usize {
+        let root_i = self.find_root(i);
+        self.size[root_i]
+    }
+
+    pub fn component_sum(&mut self, i: usize) -> i64 {
+        let root_i = self.find_root(i);
+        self.value_sum[root_i]
+    }
+
+    pub fn component_max(&mut self, i: usize) -> i64 {
+        let root_i = self.find_root(i);
+        self.value_max[root_i]
+    }
+
+    // size of the largest component
+    pub fn largest_component(&self) -> usize {
+        self.largest
+    }
+
+    // marker to later roll back to the current state
+    pub fn snapshot(&self) -> usize {
+        self.history
+            .as_ref()
+            .expect("rollback is not enabled")
+            .len()
+    }
+
+    // undo unions until only those before the snapshot remain
+    pub fn rollback(&mut self, snapshot: usize) {
+        let history = self.history.as_mut().expect("rollback is not enabled");
+
+        while history.len() > snapshot {
+            let union = history.pop().unwrap();
+            self.largest = union.largest;
+
+            if union.child == usize::MAX {
+                continue;
+            }
+
+            let child = union.child;
+            let root = self.parent[child];
+            self.parent[child] = child;
+            self.size[root] -= self.size[child];
+            self.value_sum[root] -= self.value_sum[child];
+            self.value_max[root] = union.root_max;
+            self.components += 1;
+        }
+    }
+}